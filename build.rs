@@ -0,0 +1,17 @@
+//! Embeds when the binary was built, the conditional responses are only valid
+//! for the build that rendered them (see `cache::render_version`)
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+fn main() {
+    // anything else that changes the output is in one of these
+    println!("cargo:rerun-if-changed=src");
+    println!("cargo:rerun-if-changed=Cargo.toml");
+    println!("cargo:rerun-if-changed=build.rs");
+
+    let built_at = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|v| v.as_nanos())
+        .unwrap_or_default();
+    println!("cargo:rustc-env=WEBSITE_BUILT_AT={}", built_at);
+}
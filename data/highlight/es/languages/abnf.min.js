@@ -1,12 +0,0 @@
-/*! `abnf` grammar compiled for Highlight.js 11.7.0 */
-var hljsGrammar=(()=>{"use strict";return a=>{
-const e=a.regex,s=a.COMMENT(/;/,/$/);return{name:"Augmented Backus-Naur Form",
-illegal:/[!@#$^&',?+~`|:]/,
-keywords:["ALPHA","BIT","CHAR","CR","CRLF","CTL","DIGIT","DQUOTE","HEXDIG","HTAB","LF","LWSP","OCTET","SP","VCHAR","WSP"],
-contains:[{scope:"operator",match:/=\/?/},{scope:"attribute",
-match:e.concat(/^[a-zA-Z][a-zA-Z0-9-]*/,/(?=\s*=)/)},s,{scope:"symbol",
-match:/%b[0-1]+(-[0-1]+|(\.[0-1]+)+)?/},{scope:"symbol",
-match:/%d[0-9]+(-[0-9]+|(\.[0-9]+)+)?/},{scope:"symbol",
-match:/%x[0-9A-F]+(-[0-9A-F]+|(\.[0-9A-F]+)+)?/},{scope:"symbol",
-match:/%[si](?=".*")/},a.QUOTE_STRING_MODE,a.NUMBER_MODE]}}})()
-;export default hljsGrammar;
\ No newline at end of file
@@ -1,6 +0,0 @@
-/*! `bnf` grammar compiled for Highlight.js 11.7.0 */
-var hljsGrammar=(()=>{"use strict";return a=>({name:"Backus\u2013Naur Form",
-contains:[{className:"attribute",begin:/</,end:/>/},{begin:/::=/,end:/$/,
-contains:[{begin:/</,end:/>/
-},a.C_LINE_COMMENT_MODE,a.C_BLOCK_COMMENT_MODE,a.APOS_STRING_MODE,a.QUOTE_STRING_MODE]
-}]})})();export default hljsGrammar;
\ No newline at end of file
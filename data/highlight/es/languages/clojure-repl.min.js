@@ -1,4 +0,0 @@
-/*! `clojure-repl` grammar compiled for Highlight.js 11.7.0 */
-var hljsGrammar=(()=>{"use strict";return a=>({name:"Clojure REPL",contains:[{
-className:"meta.prompt",begin:/^([\w.-]+|\s*#_)?=>/,starts:{end:/$/,
-subLanguage:"clojure"}}]})})();export default hljsGrammar;
\ No newline at end of file
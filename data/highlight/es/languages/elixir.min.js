@@ -1,34 +0,0 @@
-/*! `elixir` grammar compiled for Highlight.js 11.7.0 */
-var hljsGrammar=(()=>{"use strict";return e=>{
-const n=e.regex,a="[a-zA-Z_][a-zA-Z0-9_.]*(!|\\?)?",i={$pattern:a,
-keyword:["after","alias","and","case","catch","cond","defstruct","defguard","do","else","end","fn","for","if","import","in","not","or","quote","raise","receive","require","reraise","rescue","try","unless","unquote","unquote_splicing","use","when","with|0"],
-literal:["false","nil","true"]},s={className:"subst",begin:/#\{/,end:/\}/,
-keywords:i},c={match:/\\[\s\S]/,scope:"char.escape",relevance:0},r=[{begin:/"/,
-end:/"/},{begin:/'/,end:/'/},{begin:/\//,end:/\//},{begin:/\|/,end:/\|/},{
-begin:/\(/,end:/\)/},{begin:/\[/,end:/\]/},{begin:/\{/,end:/\}/},{begin:/</,
-end:/>/}],t=e=>({scope:"char.escape",begin:n.concat(/\\/,e),relevance:0}),d={
-className:"string",begin:"~[a-z](?=[/|([{<\"'])",
-contains:r.map((n=>e.inherit(n,{contains:[t(n.end),c,s]})))},o={
-className:"string",begin:"~[A-Z](?=[/|([{<\"'])",
-contains:r.map((n=>e.inherit(n,{contains:[t(n.end)]})))},b={className:"regex",
-variants:[{begin:"~r(?=[/|([{<\"'])",contains:r.map((a=>e.inherit(a,{
-end:n.concat(a.end,/[uismxfU]{0,7}/),contains:[t(a.end),c,s]})))},{
-begin:"~R(?=[/|([{<\"'])",contains:r.map((a=>e.inherit(a,{
-end:n.concat(a.end,/[uismxfU]{0,7}/),contains:[t(a.end)]})))}]},l={
-className:"string",contains:[e.BACKSLASH_ESCAPE,s],variants:[{begin:/"""/,
-end:/"""/},{begin:/'''/,end:/'''/},{begin:/~S"""/,end:/"""/,contains:[]},{
-begin:/~S"/,end:/"/,contains:[]},{begin:/~S'''/,end:/'''/,contains:[]},{
-begin:/~S'/,end:/'/,contains:[]},{begin:/'/,end:/'/},{begin:/"/,end:/"/}]},g={
-className:"function",beginKeywords:"def defp defmacro defmacrop",end:/\B\b/,
-contains:[e.inherit(e.TITLE_MODE,{begin:a,endsParent:!0})]},m=e.inherit(g,{
-className:"class",beginKeywords:"defimpl defmodule defprotocol defrecord",
-end:/\bdo\b|$|;/}),u=[l,b,o,d,e.HASH_COMMENT_MODE,m,g,{begin:"::"},{
-className:"symbol",begin:":(?![\\s:])",contains:[l,{
-begin:"[a-zA-Z_]\\w*[!?=]?|[-+~]@|<<|>>|=~|===?|<=>|[<>]=?|\\*\\*|[-/+%^&*~`|]|\\[\\]=?"
-}],relevance:0},{className:"symbol",begin:a+":(?!:)",relevance:0},{
-className:"title.class",begin:/(\b[A-Z][a-zA-Z0-9_]+)/,relevance:0},{
-className:"number",
-begin:"(\\b0o[0-7_]+)|(\\b0b[01_]+)|(\\b0x[0-9a-fA-F_]+)|(-?\\b[0-9][0-9_]*(\\.[0-9_]+([eE][-+]?[0-9]+)?)?)",
-relevance:0},{className:"variable",begin:"(\\$\\W)|((\\$|@@?)(\\w+))"}]
-;return s.contains=u,{name:"Elixir",aliases:["ex","exs"],keywords:i,contains:u}}
-})();export default hljsGrammar;
\ No newline at end of file
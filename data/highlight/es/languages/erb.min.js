@@ -1,5 +0,0 @@
-/*! `erb` grammar compiled for Highlight.js 11.7.0 */
-var hljsGrammar=(()=>{"use strict";return e=>({name:"ERB",subLanguage:"xml",
-contains:[e.COMMENT("<%#","%>"),{begin:"<%[%=-]?",end:"[%-]?%>",
-subLanguage:"ruby",excludeBegin:!0,excludeEnd:!0}]})})()
-;export default hljsGrammar;
\ No newline at end of file
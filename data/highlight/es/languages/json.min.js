@@ -1,8 +0,0 @@
-/*! `json` grammar compiled for Highlight.js 11.7.0 */
-var hljsGrammar=(()=>{"use strict";return e=>{
-const a=["true","false","null"],r={scope:"literal",beginKeywords:a.join(" ")}
-;return{name:"JSON",keywords:{literal:a},contains:[{className:"attr",
-begin:/"(\\.|[^\\"\r\n])*"(?=\s*:)/,relevance:1.01},{match:/[{}[\],:]/,
-className:"punctuation",relevance:0
-},e.QUOTE_STRING_MODE,r,e.C_NUMBER_MODE,e.C_LINE_COMMENT_MODE,e.C_BLOCK_COMMENT_MODE],
-illegal:"\\S"}}})();export default hljsGrammar;
\ No newline at end of file
@@ -1,5 +0,0 @@
-/*! `ldif` grammar compiled for Highlight.js 11.7.0 */
-var hljsGrammar=(()=>{"use strict";return a=>({name:"LDIF",contains:[{
-className:"attribute",match:"^dn(?=:)",relevance:10},{className:"attribute",
-match:"^\\w+(?=:)"},{className:"literal",match:"^-"},a.HASH_COMMENT_MODE]})})()
-;export default hljsGrammar;
\ No newline at end of file
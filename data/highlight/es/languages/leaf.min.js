@@ -1,8 +0,0 @@
-/*! `leaf` grammar compiled for Highlight.js 11.7.0 */
-var hljsGrammar=(()=>{"use strict";return a=>({name:"Leaf",contains:[{
-className:"function",begin:"#+[A-Za-z_0-9]*\\(",end:/ \{/,returnBegin:!0,
-excludeEnd:!0,contains:[{className:"keyword",begin:"#+"},{className:"title",
-begin:"[A-Za-z_][A-Za-z_0-9]*"},{className:"params",begin:"\\(",end:"\\)",
-endsParent:!0,contains:[{className:"string",begin:'"',end:'"'},{
-className:"variable",begin:"[A-Za-z_][A-Za-z_0-9]*"}]}]}]})})()
-;export default hljsGrammar;
\ No newline at end of file
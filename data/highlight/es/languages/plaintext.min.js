@@ -1,3 +0,0 @@
-/*! `plaintext` grammar compiled for Highlight.js 11.7.0 */
-var hljsGrammar=(()=>{"use strict";return t=>({name:"Plain text",
-aliases:["text","txt"],disableAutodetect:!0})})();export default hljsGrammar;
\ No newline at end of file
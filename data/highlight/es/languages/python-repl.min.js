@@ -1,5 +0,0 @@
-/*! `python-repl` grammar compiled for Highlight.js 11.7.0 */
-var hljsGrammar=(()=>{"use strict";return a=>({aliases:["pycon"],contains:[{
-className:"meta.prompt",starts:{end:/ |$/,starts:{end:"$",subLanguage:"python"}
-},variants:[{begin:/^>>>(?=[ ]|$)/},{begin:/^\.\.\.(?=[ ]|$)/}]}]})})()
-;export default hljsGrammar;
\ No newline at end of file
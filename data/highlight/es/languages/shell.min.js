@@ -1,5 +0,0 @@
-/*! `shell` grammar compiled for Highlight.js 11.7.0 */
-var hljsGrammar=(()=>{"use strict";return s=>({name:"Shell Session",
-aliases:["console","shellsession"],contains:[{className:"meta.prompt",
-begin:/^\s{0,3}[/~\w\d[\]()@-]*[>%$#][ ]?/,starts:{end:/[^\\](?=\s*$)/,
-subLanguage:"bash"}}]})})();export default hljsGrammar;
\ No newline at end of file
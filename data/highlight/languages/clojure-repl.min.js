@@ -1,4 +0,0 @@
-/*! `clojure-repl` grammar compiled for Highlight.js 11.7.0 */
-(()=>{var e=(()=>{"use strict";return e=>({name:"Clojure REPL",contains:[{
-className:"meta.prompt",begin:/^([\w.-]+|\s*#_)?=>/,starts:{end:/$/,
-subLanguage:"clojure"}}]})})();hljs.registerLanguage("clojure-repl",e)})();
\ No newline at end of file
@@ -1,9 +0,0 @@
-/*! `dsconfig` grammar compiled for Highlight.js 11.7.0 */
-(()=>{var e=(()=>{"use strict";return e=>({keywords:"dsconfig",contains:[{
-className:"keyword",begin:"^dsconfig",end:/\s/,excludeEnd:!0,relevance:10},{
-className:"built_in",begin:/(list|create|get|set|delete)-(\w+)/,end:/\s/,
-excludeEnd:!0,illegal:"!@#$%^&*()",relevance:10},{className:"built_in",
-begin:/--(\w+)/,end:/\s/,excludeEnd:!0},{className:"string",begin:/"/,end:/"/},{
-className:"string",begin:/'/,end:/'/},{className:"string",begin:/[\w\-?]+:\w+/,
-end:/\W/,relevance:0},{className:"string",begin:/\w+(\-\w+)*/,end:/(?=\W)/,
-relevance:0},e.HASH_COMMENT_MODE]})})();hljs.registerLanguage("dsconfig",e)})();
\ No newline at end of file
@@ -1,7 +0,0 @@
-/*! `ebnf` grammar compiled for Highlight.js 11.7.0 */
-(()=>{var e=(()=>{"use strict";return e=>{const a=e.COMMENT(/\(\*/,/\*\)/)
-;return{name:"Extended Backus-Naur Form",illegal:/\S/,contains:[a,{
-className:"attribute",begin:/^[ ]*[a-zA-Z]+([\s_-]+[a-zA-Z]+)*/},{begin:/=/,
-end:/[.;]/,contains:[a,{className:"meta",begin:/\?.*\?/},{className:"string",
-variants:[e.APOS_STRING_MODE,e.QUOTE_STRING_MODE,{begin:"`",end:"`"}]}]}]}}})()
-;hljs.registerLanguage("ebnf",e)})();
\ No newline at end of file
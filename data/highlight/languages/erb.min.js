@@ -1,5 +0,0 @@
-/*! `erb` grammar compiled for Highlight.js 11.7.0 */
-(()=>{var e=(()=>{"use strict";return e=>({name:"ERB",subLanguage:"xml",
-contains:[e.COMMENT("<%#","%>"),{begin:"<%[%=-]?",end:"[%-]?%>",
-subLanguage:"ruby",excludeBegin:!0,excludeEnd:!0}]})})()
-;hljs.registerLanguage("erb",e)})();
\ No newline at end of file
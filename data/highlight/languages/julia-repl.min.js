@@ -1,5 +0,0 @@
-/*! `julia-repl` grammar compiled for Highlight.js 11.7.0 */
-(()=>{var a=(()=>{"use strict";return a=>({name:"Julia REPL",contains:[{
-className:"meta.prompt",begin:/^julia>/,relevance:10,starts:{end:/^(?![ ]{6})/,
-subLanguage:"julia"}}],aliases:["jldoctest"]})})()
-;hljs.registerLanguage("julia-repl",a)})();
\ No newline at end of file
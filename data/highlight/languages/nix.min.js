@@ -1,13 +0,0 @@
-/*! `nix` grammar compiled for Highlight.js 11.7.0 */
-(()=>{var e=(()=>{"use strict";return e=>{const n={
-keyword:["rec","with","let","in","inherit","assert","if","else","then"],
-literal:["true","false","or","and","null"],
-built_in:["import","abort","baseNameOf","dirOf","isNull","builtins","map","removeAttrs","throw","toString","derivation"]
-},s={className:"subst",begin:/\$\{/,end:/\}/,keywords:n},a={className:"string",
-contains:[{className:"char.escape",begin:/''\$/},s],variants:[{begin:"''",
-end:"''"},{begin:'"',end:'"'}]
-},i=[e.NUMBER_MODE,e.HASH_COMMENT_MODE,e.C_BLOCK_COMMENT_MODE,a,{
-begin:/[a-zA-Z0-9-_]+(\s*=)/,returnBegin:!0,relevance:0,contains:[{
-className:"attr",begin:/\S+/,relevance:.2}]}];return s.contains=i,{name:"Nix",
-aliases:["nixos"],keywords:n,contains:i}}})();hljs.registerLanguage("nix",e)
-})();
\ No newline at end of file
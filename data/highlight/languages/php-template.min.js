@@ -1,8 +0,0 @@
-/*! `php-template` grammar compiled for Highlight.js 11.7.0 */
-(()=>{var n=(()=>{"use strict";return n=>({name:"PHP template",
-subLanguage:"xml",contains:[{begin:/<\?(php|=)?/,end:/\?>/,subLanguage:"php",
-contains:[{begin:"/\\*",end:"\\*/",skip:!0},{begin:'b"',end:'"',skip:!0},{
-begin:"b'",end:"'",skip:!0},n.inherit(n.APOS_STRING_MODE,{illegal:null,
-className:null,contains:null,skip:!0}),n.inherit(n.QUOTE_STRING_MODE,{
-illegal:null,className:null,contains:null,skip:!0})]}]})})()
-;hljs.registerLanguage("php-template",n)})();
\ No newline at end of file
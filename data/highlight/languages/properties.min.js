@@ -1,11 +0,0 @@
-/*! `properties` grammar compiled for Highlight.js 11.7.0 */
-(()=>{var e=(()=>{"use strict";return e=>{
-const t="[ \\t\\f]*",n="([^\\\\:= \\t\\f\\n]|\\\\.)+";return{name:".properties",
-disableAutodetect:!0,case_insensitive:!0,illegal:/\S/,
-contains:[e.COMMENT("^\\s*[!#]","$"),{returnBegin:!0,variants:[{
-begin:n+"[ \\t\\f]*[:=][ \\t\\f]*"},{begin:n+"[ \\t\\f]+"}],contains:[{
-className:"attr",begin:n,endsParent:!0}],starts:{
-end:"([ \\t\\f]*[:=][ \\t\\f]*|[ \\t\\f]+)",relevance:0,starts:{
-className:"string",end:/$/,relevance:0,contains:[{begin:"\\\\\\\\"},{
-begin:"\\\\\\n"}]}}},{className:"attr",begin:n+t+"$"}]}}})()
-;hljs.registerLanguage("properties",e)})();
\ No newline at end of file
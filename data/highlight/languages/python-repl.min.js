@@ -1,5 +0,0 @@
-/*! `python-repl` grammar compiled for Highlight.js 11.7.0 */
-(()=>{var a=(()=>{"use strict";return a=>({aliases:["pycon"],contains:[{
-className:"meta.prompt",starts:{end:/ |$/,starts:{end:"$",subLanguage:"python"}
-},variants:[{begin:/^>>>(?=[ ]|$)/},{begin:/^\.\.\.(?=[ ]|$)/}]}]})})()
-;hljs.registerLanguage("python-repl",a)})();
\ No newline at end of file
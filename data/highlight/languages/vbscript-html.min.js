@@ -1,4 +0,0 @@
-/*! `vbscript-html` grammar compiled for Highlight.js 11.7.0 */
-(()=>{var e=(()=>{"use strict";return e=>({name:"VBScript in HTML",
-subLanguage:"xml",contains:[{begin:"<%",end:"%>",subLanguage:"vbscript"}]})})()
-;hljs.registerLanguage("vbscript-html",e)})();
\ No newline at end of file
@@ -0,0 +1,555 @@
+use crate::cache;
+use crate::md_ex;
+use crate::post::{Article, ArticleError, CommitTimes};
+use crate::search::SearchIndex;
+
+use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+
+use std::collections::{HashMap, HashSet};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, SystemTime};
+
+use time::OffsetDateTime;
+
+/// how often the articles directory is scanned when inotify is not available
+const POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// every listed article along with its pre-rendered body
+pub struct Articles {
+    /// published articles sorted from the most recent to the oldest, drafts
+    /// and scheduled articles are only listed with `render_wip`
+    pub posts: Vec<Article>,
+    /// drafts and scheduled articles, they can only be previewed
+    pub unlisted: Vec<Article>,
+    /// when the next scheduled article is due, the index is stale after that
+    next_publish: Option<OffsetDateTime>,
+    bodies: HashMap<String, String>,
+    /// slug -> digest of the source and the header of the article
+    digests: HashMap<String, String>,
+    /// digest of every listed article, in order
+    digest: String,
+    /// when the directory was read
+    pub indexed_at: OffsetDateTime,
+    /// alias -> slug
+    aliases: HashMap<String, String>,
+    search: SearchIndex,
+}
+
+impl Default for Articles {
+    fn default() -> Self {
+        Self {
+            posts: Vec::new(),
+            unlisted: Vec::new(),
+            next_publish: None,
+            bodies: HashMap::new(),
+            digests: HashMap::new(),
+            digest: String::new(),
+            indexed_at: OffsetDateTime::UNIX_EPOCH,
+            aliases: HashMap::new(),
+            search: SearchIndex::default(),
+        }
+    }
+}
+
+impl Articles {
+    /// html rendering of the article's markdown, without the header
+    pub fn body(&self, post: &Article) -> &str {
+        self.bodies
+            .get(&post.slug)
+            .map(String::as_str)
+            .unwrap_or_default()
+    }
+
+    /// changes whenever the article's file does
+    pub fn digest_of(&self, post: &Article) -> &str {
+        self.digests
+            .get(&post.slug)
+            .map(String::as_str)
+            .unwrap_or_default()
+    }
+
+    /// changes whenever an article is added, removed, published or modified
+    pub fn digest(&self) -> &str {
+        &self.digest
+    }
+
+    /// the only way to go from a url to an article, anything that isn't the
+    /// slug of a listed article (`..`, separators, absolute paths, drafts and
+    /// scheduled articles when they are not rendered, other files) is rejected
+    pub fn get(&self, slug: &str) -> Option<&Article> {
+        self.posts.iter().find(|post| post.slug == slug)
+    }
+
+    /// articles used to be served under their file name, e.g. `foo.md`
+    pub fn get_by_file_name(&self, file_name: &str) -> Option<&Article> {
+        self.posts.iter().find(|post| post.file_name == file_name)
+    }
+
+    /// matching articles, best first, along with an html snippet of the match
+    pub fn search(&self, query: &str) -> Vec<(&Article, String)> {
+        self.search
+            .search(query)
+            .into_iter()
+            .filter_map(|hit| Some((self.get(hit.slug)?, hit.snippet)))
+            .collect()
+    }
+
+    /// a draft or a scheduled article
+    pub fn unlisted(&self, slug: &str) -> Option<&Article> {
+        self.unlisted.iter().find(|post| post.slug == slug)
+    }
+
+    /// a scheduled article was due since the index was built
+    pub fn is_stale(&self, now: OffsetDateTime) -> bool {
+        self.next_publish.is_some_and(|date| date <= now)
+    }
+
+    /// `path` is one of the `Aliases` of the article
+    pub fn get_by_alias(&self, path: &str) -> Option<&Article> {
+        let slug = self.aliases.get(&md_ex::normalize_path(path))?;
+        self.get(slug)
+    }
+
+    /// every alias along with the slug it redirects to
+    pub fn aliases(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.aliases.iter().map(|(k, v)| (k.as_str(), v.as_str()))
+    }
+
+    /// invalid articles are logged and left out
+    pub fn load(dir: &Path, render_wip: bool) -> io::Result<Self> {
+        let (articles, errors) = Self::scan(dir, render_wip)?;
+        for (path, e) in errors {
+            // skip the article, but let the author know why
+            log::error!("Invalid article '{}': {e}", path.display());
+        }
+        Ok(articles)
+    }
+
+    /// same as `load` but returns the invalid articles instead of logging them
+    pub fn scan(dir: &Path, render_wip: bool) -> io::Result<(Self, Vec<(PathBuf, ArticleError)>)> {
+        let mut loaded_files = Vec::new();
+        let mut loaded_posts = Vec::new();
+        let mut bodies = HashMap::new();
+        let mut digests = HashMap::new();
+        let mut errors = Vec::new();
+        let dir = dir.canonicalize()?;
+        let commits = CommitTimes::load(&dir);
+
+        let mut entries = std::fs::read_dir(&dir)?.collect::<io::Result<Vec<_>>>()?;
+        // when two articles share a slug, the first file name wins, unless
+        // only one of them is a draft
+        entries.sort_unstable_by_key(|entry| entry.file_name());
+
+        for entry in entries {
+            let metadata = entry.metadata()?;
+            let entry_name = entry.file_name().to_string_lossy().to_lowercase();
+
+            // drafts are always loaded so they can be previewed
+            let is_markdown = entry_name.ends_with(".md") || entry_name.ends_with(".md.wip");
+
+            if !metadata.is_file() || !is_markdown {
+                continue;
+            }
+
+            // never index a file that lives outside of the articles directory
+            let path = entry.path().canonicalize()?;
+            if !path.starts_with(&dir) {
+                continue;
+            }
+            let loaded = Article::from_file(&path, &commits).and_then(|mut post| {
+                let markdown = post.read_markdown()?;
+                post.word_count = markdown.word_count();
+                let source = std::fs::read(&post.path)?;
+                let digest = cache::digest(&[&source, format!("{:?}", post).as_bytes()]);
+                Ok((post, markdown.to_html(), markdown.plain_text(), digest))
+            });
+            match loaded {
+                Ok(v) => loaded_files.push((path, v)),
+                Err(e) => errors.push((path, e)),
+            }
+        }
+
+        // `foo.md.wip` next to `foo.md` is the next revision of a published
+        // article, it waits until it replaces the file
+        let published: HashSet<String> = loaded_files
+            .iter()
+            .filter(|(_, (post, ..))| !post.draft)
+            .map(|(_, (post, ..))| post.slug.clone())
+            .collect();
+
+        for (path, (post, body, text, digest)) in loaded_files {
+            if post.draft && published.contains(&post.slug) {
+                log::info!(
+                    "Skipping the draft '{}', '{}' is already published",
+                    path.display(),
+                    post.slug
+                );
+            } else if bodies.contains_key(&post.slug) {
+                errors.push((path, ArticleError::DuplicateSlug { slug: post.slug }));
+            } else {
+                bodies.insert(post.slug.clone(), body);
+                digests.insert(post.slug.clone(), digest);
+                loaded_posts.push((post, text));
+            }
+        }
+
+        let now = OffsetDateTime::now_utc();
+        let mut posts = Vec::new();
+        let mut unlisted = Vec::new();
+        let mut search = SearchIndex::default();
+        for (post, text) in loaded_posts {
+            if !render_wip && (post.draft || !post.is_published(now)) {
+                unlisted.push(post);
+            } else {
+                search.add(&post.slug, &post.title, text);
+                posts.push(post);
+            }
+        }
+
+        posts.sort_unstable_by(|s, o| {
+            s.date
+                .cmp(&o.date)
+                .then_with(|| s.slug.cmp(&o.slug))
+                // make the oldest articles appear at the end
+                .reverse()
+        });
+
+        let listed: Vec<&[u8]> = posts
+            .iter()
+            .filter_map(|post| digests.get(&post.slug))
+            .map(|digest: &String| digest.as_bytes())
+            .collect();
+        let digest = cache::digest(&listed);
+
+        let next_publish = unlisted
+            .iter()
+            .filter(|post| !post.draft && !post.is_published(now))
+            .map(|post| post.date)
+            .min();
+
+        // the most recent article keeps an alias used more than once
+        let mut aliases: HashMap<String, String> = HashMap::new();
+        for post in posts.iter() {
+            for alias in post.aliases.iter() {
+                match aliases.get(alias) {
+                    Some(other) => log::warn!(
+                        "Ignoring alias '{}' of '{}', it is already used by '{}'",
+                        alias,
+                        post.slug,
+                        other
+                    ),
+                    None => {
+                        aliases.insert(alias.clone(), post.slug.clone());
+                    }
+                }
+            }
+        }
+
+        Ok((
+            Self {
+                posts,
+                unlisted,
+                next_publish,
+                bodies,
+                digests,
+                digest,
+                indexed_at: now,
+                aliases,
+                search,
+            },
+            errors,
+        ))
+    }
+}
+
+/// Keeps the parsed articles in memory, the articles directory is only read
+/// again after something in it changed
+pub struct ArticleIndex {
+    dir: PathBuf,
+    render_wip: bool,
+    current: RwLock<Arc<Articles>>,
+    dirty: Arc<AtomicBool>,
+    // the watcher stops when dropped
+    _watcher: Option<RecommendedWatcher>,
+}
+
+impl ArticleIndex {
+    pub fn new(dir: impl Into<PathBuf>, render_wip: bool) -> Self {
+        let dir = dir.into();
+        let dirty = Arc::new(AtomicBool::new(true));
+
+        let watcher = match watch(&dir, dirty.clone()) {
+            Ok(v) => Some(v),
+            Err(e) => {
+                log::warn!(
+                    "Unable to watch '{}' ({e}), polling every {}s instead",
+                    dir.display(),
+                    POLL_INTERVAL.as_secs()
+                );
+                poll(dir.clone(), dirty.clone());
+                None
+            }
+        };
+
+        Self {
+            dir,
+            render_wip,
+            current: RwLock::new(Arc::new(Articles::default())),
+            dirty,
+            _watcher: watcher,
+        }
+    }
+
+    /// rebuilds the index if the directory changed since the last call, or if
+    /// a scheduled article is now published
+    pub async fn articles(&self) -> io::Result<Arc<Articles>> {
+        if self
+            .current
+            .read()
+            .unwrap()
+            .is_stale(OffsetDateTime::now_utc())
+        {
+            self.dirty.store(true, Ordering::Release);
+        }
+        if self.dirty.swap(false, Ordering::AcqRel) {
+            let dir = self.dir.clone();
+            let render_wip = self.render_wip;
+            let res = actix_web::web::block(move || Articles::load(&dir, render_wip))
+                .await
+                .map_err(io::Error::other)
+                .and_then(|v| v);
+            match res {
+                Ok(articles) => {
+                    log::info!(
+                        "Indexed {} articles from '{}'",
+                        articles.posts.len(),
+                        self.dir.display()
+                    );
+                    *self.current.write().unwrap() = Arc::new(articles);
+                }
+                Err(e) => {
+                    // try again on the next request
+                    self.dirty.store(true, Ordering::Release);
+                    return Err(e);
+                }
+            }
+        }
+        Ok(self.current.read().unwrap().clone())
+    }
+}
+
+fn watch(dir: &Path, dirty: Arc<AtomicBool>) -> notify::Result<RecommendedWatcher> {
+    let mut watcher =
+        notify::recommended_watcher(move |res: notify::Result<notify::Event>| match res {
+            Ok(_) => dirty.store(true, Ordering::Release),
+            Err(e) => log::error!("Ran into error while watching articles: {e}"),
+        })?;
+    watcher.watch(dir, RecursiveMode::Recursive)?;
+    Ok(watcher)
+}
+
+/// mtime polling, used when the platform can't notify us of changes
+fn poll(dir: PathBuf, dirty: Arc<AtomicBool>) {
+    std::thread::spawn(move || {
+        let mut last = fingerprint(&dir);
+        loop {
+            std::thread::sleep(POLL_INTERVAL);
+            let new = fingerprint(&dir);
+            if new != last {
+                dirty.store(true, Ordering::Release);
+                last = new;
+            }
+        }
+    });
+}
+
+fn fingerprint(dir: &Path) -> Vec<(PathBuf, Option<SystemTime>, u64)> {
+    let mut entries: Vec<_> = std::fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|entry| {
+            let metadata = entry.metadata().ok();
+            (
+                entry.path(),
+                metadata.as_ref().and_then(|m| m.modified().ok()),
+                metadata.map(|m| m.len()).unwrap_or_default(),
+            )
+        })
+        .collect();
+    entries.sort_unstable();
+    entries
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use actix_web::http::StatusCode;
+    use actix_web::test::{call_service, init_service, TestRequest};
+    use actix_web::{web, App};
+
+    const HELLO: &str = "---\nTitle: Hello\nDate: 2023-04-12\n---\n# Hello\n";
+
+    /// `<tmp>/<name>/articles` filled with `files`, the caller removes `<tmp>/<name>`
+    fn fixture(name: &str, files: &[(&str, &str)]) -> (PathBuf, PathBuf) {
+        let root = std::env::temp_dir().join(format!("{}_{}", name, std::process::id()));
+        let dir = root.join("articles");
+        std::fs::create_dir_all(&dir).unwrap();
+        for (file_name, content) in files {
+            std::fs::write(dir.join(file_name), content).unwrap();
+        }
+        (root, dir)
+    }
+
+    #[actix_web::test]
+    async fn test_get_rejects_paths() {
+        let (root, dir) = fixture(
+            "article_index_paths",
+            &[("hello.md", HELLO), ("notes.txt", HELLO)],
+        );
+        std::fs::write(root.join("secret.md"), HELLO).unwrap();
+
+        let app = init_service(
+            App::new()
+                .app_data(web::Data::new(ArticleIndex::new(&dir, false)))
+                .service(crate::article),
+        )
+        .await;
+        let get = |uri: &str| TestRequest::get().uri(uri).to_request();
+
+        let res = call_service(&app, get("/article/hello")).await;
+        assert_eq!(res.status(), StatusCode::OK);
+        let res = call_service(&app, get("/article/hello.md")).await;
+        assert_eq!(res.status(), StatusCode::MOVED_PERMANENTLY);
+
+        for uri in [
+            "/article/secret",
+            "/article/secret.md",
+            "/article/..%2Fsecret.md",
+            "/article/..%5Csecret.md",
+            "/article/..%2F..%2Fsecret",
+            "/article/%2Fetc%2Fpasswd",
+            "/article/..",
+            "/article/notes.txt",
+            "/article/",
+        ] {
+            let res = call_service(&app, get(uri)).await;
+            assert_eq!(res.status(), StatusCode::NOT_FOUND, "{}", uri);
+        }
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[actix_web::test]
+    async fn test_index_follows_changes() {
+        let (root, dir) = fixture("article_index_changes", &[("hello.md", HELLO)]);
+        let article_index = ArticleIndex::new(&dir, false);
+        assert_eq!(article_index.articles().await.unwrap().posts.len(), 1);
+
+        let other = "---\nTitle: Other\nDate: 2023-04-13\n---\n";
+        std::fs::write(dir.join("other.md"), other).unwrap();
+        // the watcher is asynchronous, polling takes up to `POLL_INTERVAL`
+        let mut posts = 1;
+        for _ in 0..(POLL_INTERVAL.as_millis() / 50 * 2) {
+            posts = article_index.articles().await.unwrap().posts.len();
+            if posts == 2 {
+                break;
+            }
+            actix_web::rt::time::sleep(Duration::from_millis(50)).await;
+        }
+        assert_eq!(posts, 2);
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_duplicate_slug() {
+        let duplicate = "---\nTitle: Hello\nDate: 2023-04-12\nSlug: Hello\n---\n";
+        let (root, dir) = fixture(
+            "article_index_duplicate",
+            &[("hello.md", HELLO), ("hello_again.md", duplicate)],
+        );
+
+        let (articles, errors) = Articles::scan(&dir, false).unwrap();
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].0.ends_with("hello_again.md"));
+        assert!(matches!(errors[0].1, ArticleError::DuplicateSlug { .. }));
+        assert_eq!(articles.get("hello").unwrap().file_name, "hello.md");
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_draft_of_a_published_article() {
+        // the draft sorts first, yet the published article keeps its slug
+        let draft = "---\nTitle: Hello, again\nDate: 2023-04-12\nSlug: hello\n---\n";
+        let (root, dir) = fixture(
+            "article_index_revision",
+            &[
+                ("a_new_hello.md.wip", draft),
+                ("hello.md", HELLO),
+                ("hello.md.wip", HELLO),
+            ],
+        );
+
+        for render_wip in [false, true] {
+            let (articles, errors) = Articles::scan(&dir, render_wip).unwrap();
+            assert!(errors.is_empty(), "{:?}", errors);
+            assert_eq!(articles.get("hello").unwrap().file_name, "hello.md");
+            assert_eq!(articles.posts.len(), 1);
+            assert!(articles.unlisted("hello").is_none());
+        }
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_drafts_are_unlisted() {
+        let (root, dir) = fixture(
+            "article_index_drafts",
+            &[("hello.md", HELLO), ("draft.md.wip", HELLO)],
+        );
+
+        let (articles, errors) = Articles::scan(&dir, false).unwrap();
+        assert!(errors.is_empty());
+        assert!(articles.get("draft").is_none());
+        assert!(articles.get_by_file_name("draft.md.wip").is_none());
+        assert!(articles.unlisted("draft").is_some());
+        assert!(articles
+            .search("hello")
+            .iter()
+            .all(|(post, _)| post.slug != "draft"));
+
+        let (articles, _) = Articles::scan(&dir, true).unwrap();
+        assert!(articles.get("draft").is_some());
+        assert!(articles.unlisted("draft").is_none());
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_scheduled_articles() {
+        let scheduled = "---\nTitle: Later\nDate: 2999-01-01 09:00 +01:00\n---\n";
+        let (root, dir) = fixture(
+            "article_index_scheduled",
+            &[("hello.md", HELLO), ("later.md", scheduled)],
+        );
+
+        let (articles, _) = Articles::scan(&dir, false).unwrap();
+        assert!(articles.get("later").is_none());
+        assert!(articles.unlisted("later").is_some());
+        assert!(!articles.is_stale(OffsetDateTime::now_utc()));
+        assert!(articles.is_stale(time::macros::datetime!(2999-01-01 8:00 UTC)));
+
+        let (articles, _) = Articles::scan(&dir, true).unwrap();
+        assert!(articles.get("later").is_some());
+        // nothing to wait for when scheduled articles are already listed
+        assert!(!articles.is_stale(time::macros::datetime!(2999-01-01 8:00 UTC)));
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,220 @@
+//! Conditional responses for the rendered routes. Their ETag is a digest of
+//! what they are rendered from (the articles, `index.md`) and of the render
+//! version: the build along with the settings and pages every page depends on.
+//! A client with a current copy gets a 304 before anything is rendered.
+//!
+//! `Last-Modified` is the date of the content, e.g. the article's `Updated`,
+//! but it doesn't cover everything the page is rendered from. The render
+//! version only changes when the server starts, so `If-Modified-Since` is
+//! only answered with a 304 for dates after that, and after the year the
+//! footer shows.
+
+use crate::article_index::Articles;
+use crate::error::AppError;
+use crate::post::Article;
+use crate::{config, pages};
+
+use actix_web::http::header::{
+    ETag, EntityTag, IfModifiedSince, IfNoneMatch, LastModified, TryIntoHeaderValue,
+};
+use actix_web::{HttpMessage, HttpRequest, HttpResponse};
+use openssl::sha::Sha256;
+
+use std::sync::OnceLock;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use time::{Date, OffsetDateTime};
+
+static RENDER_VERSION: OnceLock<(String, OffsetDateTime)> = OnceLock::new();
+
+/// hex encoded sha256 of `parts`, truncated to 128 bits
+pub fn digest(parts: &[&[u8]]) -> String {
+    let mut hasher = Sha256::new();
+    for part in parts {
+        // the length keeps `["ab", "c"]` and `["a", "bc"]` apart
+        hasher.update(&(part.len() as u64).to_le_bytes());
+        hasher.update(part);
+    }
+    hasher.finish()[..16]
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+/// changes with the build, the configuration and the pages, all of them are
+/// only read at startup, along with when it was computed
+fn render_version() -> &'static (String, OffsetDateTime) {
+    RENDER_VERSION.get_or_init(|| {
+        let version = digest(&[
+            env!("WEBSITE_BUILT_AT").as_bytes(),
+            format!("{:?}", config::get()).as_bytes(),
+            format!("{:?}", pages::get()).as_bytes(),
+        ]);
+        (version, OffsetDateTime::now_utc())
+    })
+}
+
+pub struct Validators {
+    etag: EntityTag,
+    last_modified: SystemTime,
+    /// last change of anything the response is rendered from
+    changed_at: SystemTime,
+}
+
+impl Validators {
+    /// `sources` are the contents, or digests, the response is rendered from,
+    /// `last_modified` the date of the content
+    pub fn new(sources: &[&[u8]], last_modified: OffsetDateTime) -> Self {
+        let (version, computed_at) = render_version();
+        // the footer's copyright notice ends with the current year
+        let now = OffsetDateTime::now_utc();
+        let year = now.year().to_le_bytes();
+        let mut parts = vec![version.as_bytes(), year.as_slice()];
+        parts.extend_from_slice(sources);
+
+        let new_year =
+            Date::from_ordinal_date(now.year(), 1).map_or(now, |date| date.midnight().assume_utc());
+        Self {
+            // weak, the body differs once `Compress` has encoded it
+            etag: EntityTag::new_weak(digest(&parts)),
+            last_modified: last_modified.into(),
+            changed_at: last_modified.max(*computed_at).max(new_year).into(),
+        }
+    }
+
+    /// something the response is rendered from changed at `time`
+    fn changed_at(mut self, time: OffsetDateTime) -> Self {
+        self.changed_at = self.changed_at.max(time.into());
+        self
+    }
+
+    /// for the routes listing the articles, they change along with any of them
+    pub fn listing(articles: &Articles) -> Self {
+        Self::new(&[articles.digest().as_bytes()], articles.indexed_at)
+    }
+
+    /// dated by `Updated`, which can be a commit older than the file: it
+    /// may not be committed yet
+    pub fn article(articles: &Articles, post: &Article) -> Self {
+        let modified = std::fs::metadata(&post.path)
+            .and_then(|m| m.modified())
+            .map(OffsetDateTime::from)
+            .unwrap_or(post.updated);
+        Self::new(&[articles.digest_of(post).as_bytes()], post.updated)
+            .changed_at(modified)
+            .changed_at(articles.indexed_at)
+    }
+
+    /// the client's copy is current, `If-Modified-Since` is ignored when
+    /// `If-None-Match` is given
+    fn is_fresh(&self, req: &HttpRequest) -> bool {
+        match req.get_header::<IfNoneMatch>() {
+            Some(IfNoneMatch::Any) => true,
+            Some(IfNoneMatch::Items(tags)) => tags.iter().any(|tag| tag.weak_eq(&self.etag)),
+            None => match req.get_header::<IfModifiedSince>() {
+                // http dates are precise to the second
+                Some(IfModifiedSince(since)) => {
+                    secs(self.changed_at) <= secs(SystemTime::from(since))
+                }
+                None => false,
+            },
+        }
+    }
+
+    /// a 304 when the client's copy is current, otherwise the body `render`
+    /// returns
+    pub fn respond(
+        &self,
+        req: &HttpRequest,
+        content_type: impl TryIntoHeaderValue,
+        render: impl FnOnce() -> Result<String, AppError>,
+    ) -> Result<HttpResponse, AppError> {
+        let fresh = self.is_fresh(req);
+        let mut builder = if fresh {
+            HttpResponse::NotModified()
+        } else {
+            HttpResponse::Ok()
+        };
+        builder
+            .insert_header(ETag(self.etag.clone()))
+            .insert_header(LastModified(self.last_modified.into()));
+
+        if fresh {
+            return Ok(builder.finish());
+        }
+        Ok(builder.content_type(content_type).body(render()?))
+    }
+}
+
+fn secs(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .map(|v| v.as_secs())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use actix_web::http::header::{self, HttpDate};
+    use actix_web::http::StatusCode;
+    use actix_web::test::TestRequest;
+
+    #[test]
+    fn test_respond() {
+        let date = time::macros::datetime!(2023-04-12 9:30 UTC);
+        let validators = Validators::new(&[b"hello"], date);
+        let render = || Ok("hello".to_string());
+
+        let res = validators
+            .respond(
+                &TestRequest::default().to_http_request(),
+                "text/plain",
+                render,
+            )
+            .unwrap();
+        assert_eq!(res.status(), StatusCode::OK);
+        let etag = res.headers().get(header::ETAG).unwrap().clone();
+        let last_modified = res.headers().get(header::LAST_MODIFIED).unwrap().clone();
+
+        let req = TestRequest::default()
+            .insert_header((header::IF_NONE_MATCH, etag.clone()))
+            .to_http_request();
+        let res = validators
+            .respond(&req, "text/plain", || panic!("rendered a fresh copy"))
+            .unwrap();
+        assert_eq!(res.status(), StatusCode::NOT_MODIFIED);
+        assert_eq!(res.headers().get(header::ETAG), Some(&etag));
+
+        assert_eq!(last_modified, "Wed, 12 Apr 2023 09:30:00 GMT");
+
+        // older than this server, the copy may have been rendered differently
+        let req = TestRequest::default()
+            .insert_header((header::IF_MODIFIED_SINCE, last_modified.clone()))
+            .to_http_request();
+        let res = validators.respond(&req, "text/plain", render).unwrap();
+        assert_eq!(res.status(), StatusCode::OK);
+
+        let now = HttpDate::from(SystemTime::now());
+        let req = TestRequest::default()
+            .insert_header((header::IF_MODIFIED_SINCE, now))
+            .to_http_request();
+        let res = validators.respond(&req, "text/plain", render).unwrap();
+        assert_eq!(res.status(), StatusCode::NOT_MODIFIED);
+
+        // the etag wins over the date
+        let req = TestRequest::default()
+            .insert_header((header::IF_NONE_MATCH, "\"stale\""))
+            .insert_header((header::IF_MODIFIED_SINCE, last_modified))
+            .to_http_request();
+        let res = validators.respond(&req, "text/plain", render).unwrap();
+        assert_eq!(res.status(), StatusCode::OK);
+
+        let other = Validators::new(&[b"hello again"], date);
+        let req = TestRequest::default()
+            .insert_header((header::IF_NONE_MATCH, etag))
+            .to_http_request();
+        let res = other.respond(&req, "text/plain", render).unwrap();
+        assert_eq!(res.status(), StatusCode::OK);
+    }
+}
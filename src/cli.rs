@@ -0,0 +1,236 @@
+use crate::article_index::Articles;
+use crate::config::{self, Config};
+use crate::preview;
+
+use clap::{Args, Parser, Subcommand};
+
+use std::error::Error;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use time::OffsetDateTime;
+
+#[derive(Parser)]
+#[command(about = "Serves or exports a blog written in markdown")]
+pub struct Cli {
+    /// defaults to ./website.toml, if it exists
+    #[arg(long, global = true)]
+    pub config: Option<PathBuf>,
+    /// serves the website when omitted
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Serve the website
+    Serve(Box<ServeArgs>),
+    /// Render the website to static files
+    Build {
+        /// directory the website is written to
+        #[arg(long, default_value = "./public")]
+        out: PathBuf,
+    },
+    /// Create a draft article in the articles directory
+    New {
+        /// file name of the article, without the extension
+        slug: String,
+    },
+    /// Parse every article, drafts included, and report invalid headers
+    Check,
+    /// Print the link a draft or a scheduled article can be previewed at
+    Preview {
+        /// slug of the article
+        slug: String,
+    },
+}
+
+/// Every flag overrides the setting of the same name
+#[derive(Args, Default)]
+pub struct ServeArgs {
+    /// address both servers listen on
+    #[arg(long)]
+    ip_bind: Option<String>,
+    #[arg(long)]
+    http_port: Option<u16>,
+    #[arg(long)]
+    https_port: Option<u16>,
+    /// https is only served when both the key and the certificate are set
+    #[arg(long)]
+    private_key_filepath: Option<String>,
+    #[arg(long)]
+    certificate_chain_filepath: Option<String>,
+    /// served under /data
+    #[arg(long)]
+    fs_data_path: Option<String>,
+    /// served under /media
+    #[arg(long)]
+    fs_media_path: Option<String>,
+    #[arg(long)]
+    fs_articles_path: Option<String>,
+    /// markdown pages served at the root of the website
+    #[arg(long)]
+    fs_pages_path: Option<String>,
+    /// answers to the ACME challenges, only used with https
+    #[arg(long)]
+    fs_acme_challenge_path: Option<String>,
+    /// markdown shown on the home page
+    #[arg(long)]
+    index_md_filepath: Option<String>,
+    /// toml file mapping old paths to their new location
+    #[arg(long)]
+    redirects_filepath: Option<String>,
+    /// publish the drafts and the scheduled articles, as if they were out
+    #[arg(long)]
+    render_wip: bool,
+    /// key of the draft preview links
+    #[arg(long)]
+    preview_secret: Option<String>,
+    /// show the details of errors in the error pages
+    #[arg(long)]
+    debug: bool,
+}
+
+impl ServeArgs {
+    pub fn apply(&self, config: &mut Config) {
+        if let Some(v) = &self.ip_bind {
+            config.ip_bind = v.clone();
+        }
+        if let Some(v) = self.http_port {
+            config.http_port = v;
+        }
+        if let Some(v) = self.https_port {
+            config.https_port = v;
+        }
+        if let Some(v) = &self.private_key_filepath {
+            config.private_key_filepath = Some(v.clone());
+        }
+        if let Some(v) = &self.certificate_chain_filepath {
+            config.certificate_chain_filepath = Some(v.clone());
+        }
+        if let Some(v) = &self.fs_data_path {
+            config.fs_data_path = v.clone();
+        }
+        if let Some(v) = &self.fs_media_path {
+            config.fs_media_path = v.clone();
+        }
+        if let Some(v) = &self.fs_articles_path {
+            config.fs_articles_path = v.clone();
+        }
+        if let Some(v) = &self.fs_pages_path {
+            config.fs_pages_path = v.clone();
+        }
+        if let Some(v) = &self.fs_acme_challenge_path {
+            config.fs_acme_challenge_path = v.clone();
+        }
+        if let Some(v) = &self.index_md_filepath {
+            config.index_md_filepath = v.clone();
+        }
+        if let Some(v) = &self.redirects_filepath {
+            config.redirects_filepath = v.clone();
+        }
+        if self.render_wip {
+            config.render_wip = true;
+        }
+        if let Some(v) = &self.preview_secret {
+            config.preview_secret = Some(v.clone());
+        }
+        if self.debug {
+            config.debug = true;
+        }
+    }
+}
+
+/// `my_new_article` -> `My new article`
+fn title_from_slug(slug: &str) -> String {
+    let words = slug.replace(['_', '-'], " ");
+    let mut chars = words.trim().chars();
+    match chars.next() {
+        Some(c) => c.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// creates `<slug>.md.wip`, drafts are only listed with `RENDER_WIP`, see
+/// `preview_link` to share them
+pub fn new_article(slug: &str) -> Result<PathBuf, Box<dyn Error>> {
+    if slug.is_empty() || slug.starts_with('.') || slug.contains(['/', '\\']) {
+        return Err(format!("invalid article slug '{}'", slug).into());
+    }
+
+    let path = Path::new(config::get().fs_articles_path.as_str()).join(format!("{}.md.wip", slug));
+    let title = title_from_slug(slug);
+    let date = OffsetDateTime::now_utc().date();
+
+    let mut file = OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&path)
+        .map_err(|e| format!("unable to create '{}': {}", path.display(), e))?;
+    write!(
+        file,
+        "---\nTitle: {title}\nAuthor: {}\nDate: {date}\n---\n# {title}\n\n",
+        config::get().default_author
+    )?;
+
+    Ok(path)
+}
+
+/// prints every invalid article, fails if there is at least one
+pub fn check() -> Result<(), Box<dyn Error>> {
+    let dir = Path::new(config::get().fs_articles_path.as_str());
+    let (articles, errors) = Articles::scan(dir, true)?;
+
+    for (path, e) in errors.iter() {
+        println!("{}: {}", path.display(), e);
+    }
+    println!(
+        "{} valid article(s), {} invalid article(s) in '{}'",
+        articles.posts.len(),
+        errors.len(),
+        dir.display()
+    );
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(format!("found {} invalid article(s)", errors.len()).into())
+    }
+}
+
+/// `<base_url>/preview/<token>`, the server has to use the same secret
+pub fn preview_link(slug: &str) -> Result<String, Box<dyn Error>> {
+    let secret = config::get()
+        .preview_secret
+        .as_deref()
+        .ok_or("previews are disabled, set `preview_secret` first")?;
+
+    let dir = Path::new(config::get().fs_articles_path.as_str());
+    let articles = Articles::load(dir, false)?;
+    if articles.unlisted(slug).is_none() {
+        return Err(format!(
+            "no draft or scheduled article with the slug '{}' in '{}'",
+            slug,
+            dir.display()
+        )
+        .into());
+    }
+
+    Ok(format!(
+        "{}/preview/{}",
+        config::get().base_url,
+        preview::token(secret, slug)
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_title_from_slug() {
+        assert_eq!(title_from_slug("my_new-article"), "My new article");
+        assert_eq!(title_from_slug(""), "");
+    }
+}
@@ -0,0 +1,370 @@
+//! Settings are read from `website.toml` (every key is optional), then
+//! overridden by environment variables of the same name in upper case, then by
+//! the command line flags of `website serve`
+
+use serde::Deserialize;
+
+use std::env::vars;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use time::OffsetDateTime;
+
+/// read when `--config` isn't given, it is fine for it not to exist
+pub const DEFAULT_CONFIG_FILEPATH: &str = "./website.toml";
+
+static CONFIG: OnceLock<Config> = OnceLock::new();
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub fs_data_path: String,
+    pub fs_media_path: String,
+    pub fs_articles_path: String,
+    /// every `<slug>.md` in it is served at `/<slug>`
+    pub fs_pages_path: String,
+    /// served under `/.well-known/acme-challenge` by the http listener
+    pub fs_acme_challenge_path: String,
+    pub ip_bind: String,
+    pub http_port: u16,
+    pub https_port: u16,
+    pub private_key_filepath: Option<String>,
+    pub certificate_chain_filepath: Option<String>,
+    /// `max-age` of the `Strict-Transport-Security` header sent with tls, in
+    /// seconds, the header is left out when it is 0
+    pub hsts_max_age: u64,
+    pub hsts_include_subdomains: bool,
+    pub index_md_filepath: String,
+    /// toml file of `"/old/path" = "/new/path"` redirects, it may not exist
+    pub redirects_filepath: String,
+    /// publishes the drafts and the scheduled articles: listed, in the feeds
+    /// and served at `/article/`
+    pub render_wip: bool,
+    /// show the details of errors to visitors, `WEBSITE_DEBUG=true` in the
+    /// environment
+    pub debug: bool,
+    /// key of the draft preview links, previews are disabled when unset
+    pub preview_secret: Option<String>,
+    /// root of the website used in feeds, without a trailing slash
+    pub base_url: String,
+    /// title of the home page and of the feeds
+    pub site_name: String,
+    /// holder of the copyright shown in the footer and the feeds
+    pub owner: String,
+    /// first year of the copyright notice
+    pub start_year: i32,
+    /// used when an article doesn't name its author
+    pub default_author: String,
+    /// reading speed used to estimate how long an article takes to read
+    pub words_per_minute: u32,
+    /// where the footer's "open a pr" link points, hidden when unset
+    pub source_url: Option<String>,
+    /// links of the header, in order, followed by the pages with a `Nav` key
+    pub nav: Vec<Link>,
+    /// profiles on other websites, shown in the header after `nav`
+    pub social: Vec<Link>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct Link {
+    pub name: String,
+    pub url: String,
+}
+
+impl Link {
+    fn new(name: &str, url: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            url: url.to_string(),
+        }
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            fs_data_path: "./data".to_string(),
+            fs_media_path: "./media".to_string(),
+            fs_articles_path: "./articles".to_string(),
+            fs_pages_path: "./pages".to_string(),
+            fs_acme_challenge_path: "./.well-known/acme-challenge".to_string(),
+            ip_bind: "0.0.0.0".to_string(),
+            http_port: 80,
+            https_port: 443,
+            private_key_filepath: None,
+            certificate_chain_filepath: None,
+            // a year
+            hsts_max_age: 31_536_000,
+            hsts_include_subdomains: false,
+            index_md_filepath: "./data/index.md".to_string(),
+            redirects_filepath: "./redirects.toml".to_string(),
+            render_wip: false,
+            debug: false,
+            preview_secret: None,
+            // neutral, an instance without settings shouldn't pass for
+            // another one
+            base_url: "http://localhost".to_string(),
+            site_name: "Blog".to_string(),
+            owner: "Anonymous".to_string(),
+            start_year: OffsetDateTime::now_utc().year(),
+            default_author: "Anonymous".to_string(),
+            words_per_minute: 200,
+            source_url: None,
+            nav: vec![
+                Link::new("home", "/"),
+                Link::new("articles", "/articles"),
+                Link::new("tags", "/tags"),
+                Link::new("search", "/search"),
+                Link::new("rss", "/rss.xml"),
+            ],
+            social: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ConfigError {
+    IO {
+        path: PathBuf,
+        error: io::Error,
+    },
+    Parse {
+        path: PathBuf,
+        error: toml::de::Error,
+    },
+    InvalidEnv {
+        key: String,
+        value: String,
+    },
+    SamePort {
+        port: u16,
+    },
+    ZeroWordsPerMinute,
+}
+
+impl std::fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfigError::IO { path, error } => f.write_str(&format!(
+                "Unable to read config file '{}': {}",
+                path.display(),
+                error
+            )),
+            ConfigError::Parse { path, error } => f.write_str(&format!(
+                "Invalid config file '{}': {}",
+                path.display(),
+                error
+            )),
+            ConfigError::InvalidEnv { key, value } => {
+                f.write_str(&format!("Invalid value '{}' for env var '{}'", value, key))
+            }
+            ConfigError::SamePort { port } => f.write_str(&format!(
+                "Cannot use the same port ({}) for http and https",
+                port
+            )),
+            ConfigError::ZeroWordsPerMinute => {
+                f.write_str("words_per_minute has to be greater than 0")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+fn parse_env<T: std::str::FromStr>(key: &str, value: &str) -> Result<T, ConfigError> {
+    value.parse().map_err(|_| ConfigError::InvalidEnv {
+        key: key.to_string(),
+        value: value.to_string(),
+    })
+}
+
+impl Config {
+    /// `path` has to exist when given, otherwise `DEFAULT_CONFIG_FILEPATH` is
+    /// read if it exists
+    pub fn load(path: Option<&Path>) -> Result<Self, ConfigError> {
+        let (path, required) = match path {
+            Some(v) => (v, true),
+            None => (Path::new(DEFAULT_CONFIG_FILEPATH), false),
+        };
+
+        let mut config = match std::fs::read_to_string(path) {
+            Ok(v) => toml::from_str(&v).map_err(|error| ConfigError::Parse {
+                path: path.to_path_buf(),
+                error,
+            })?,
+            Err(e) if !required && e.kind() == io::ErrorKind::NotFound => Self::default(),
+            Err(error) => {
+                return Err(ConfigError::IO {
+                    path: path.to_path_buf(),
+                    error,
+                })
+            }
+        };
+
+        config.apply_env(vars())?;
+        Ok(config)
+    }
+
+    fn apply_env(
+        &mut self,
+        vars: impl IntoIterator<Item = (String, String)>,
+    ) -> Result<(), ConfigError> {
+        for (key, value) in vars {
+            match key.as_str() {
+                "FS_DATA_PATH" => self.fs_data_path = value,
+                "FS_MEDIA_PATH" => self.fs_media_path = value,
+                "FS_ARTICLES_PATH" => self.fs_articles_path = value,
+                "FS_PAGES_PATH" => self.fs_pages_path = value,
+                "FS_ACME_CHALLENGE_PATH" => self.fs_acme_challenge_path = value,
+                "IP_BIND" => self.ip_bind = value,
+                "HTTP_PORT" => self.http_port = parse_env(&key, &value)?,
+                "HTTPS_PORT" => self.https_port = parse_env(&key, &value)?,
+                "PRIVATE_KEY_FILEPATH" => self.private_key_filepath = Some(value),
+                "CERTIFICATE_CHAIN_FILEPATH" => self.certificate_chain_filepath = Some(value),
+                "HSTS_MAX_AGE" => self.hsts_max_age = parse_env(&key, &value)?,
+                "HSTS_INCLUDE_SUBDOMAINS" => {
+                    self.hsts_include_subdomains = parse_env(&key, &value)?
+                }
+                "INDEX_MD_FILEPATH" => self.index_md_filepath = value,
+                "REDIRECTS_FILEPATH" => self.redirects_filepath = value,
+                "RENDER_WIP" => self.render_wip = parse_env(&key, &value)?,
+                // namespaced, a generic `DEBUG` set for another tool would
+                // leak the details of errors to visitors
+                "WEBSITE_DEBUG" => self.debug = parse_env(&key, &value)?,
+                "PREVIEW_SECRET" => self.preview_secret = Some(value),
+                "BASE_URL" => self.base_url = value,
+                "SITE_NAME" => self.site_name = value,
+                "OWNER" => self.owner = value,
+                "START_YEAR" => self.start_year = parse_env(&key, &value)?,
+                "DEFAULT_AUTHOR" => self.default_author = value,
+                "WORDS_PER_MINUTE" => self.words_per_minute = parse_env(&key, &value)?,
+                "SOURCE_URL" => self.source_url = Some(value),
+                _ => (),
+            }
+        }
+        Ok(())
+    }
+
+    pub fn validate(mut self) -> Result<Self, ConfigError> {
+        if self.http_port == self.https_port {
+            return Err(ConfigError::SamePort {
+                port: self.http_port,
+            });
+        }
+        if self.words_per_minute == 0 {
+            return Err(ConfigError::ZeroWordsPerMinute);
+        }
+        self.base_url = self.base_url.trim_end_matches('/').to_string();
+        // an empty secret would make every token public
+        if self.preview_secret.as_deref() == Some("") {
+            self.preview_secret = None;
+        }
+        Ok(self)
+    }
+}
+
+/// has to be called once, before anything reads the configuration
+pub fn init(config: Config) {
+    if CONFIG.set(config).is_err() {
+        log::warn!("Configuration was already loaded, ignoring the new one");
+    }
+}
+
+pub fn get() -> &'static Config {
+    CONFIG.get_or_init(Config::default)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_config() {
+        let config: Config = toml::from_str(
+            r#"
+            http_port = 8080
+            site_name = "Another imperfect blog"
+
+            [[nav]]
+            name = "home"
+            url = "/"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.http_port, 8080);
+        assert_eq!(config.site_name, "Another imperfect blog");
+        assert_eq!(config.fs_data_path, "./data");
+        assert_eq!(config.nav.len(), 1);
+        assert_eq!(config.nav[0].url, "/");
+        // nothing links to another instance's author unless configured
+        assert_eq!(config.base_url, "http://localhost");
+        assert_eq!(config.owner, "Anonymous");
+        assert!(config.social.is_empty());
+        assert_eq!(config.source_url, None);
+
+        assert!(toml::from_str::<Config>("http_prot = 8080").is_err());
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(Config::default().validate().is_ok());
+
+        let config: Config = toml::from_str("https_port = 80").unwrap();
+        assert!(matches!(
+            config.validate(),
+            Err(ConfigError::SamePort { port: 80 })
+        ));
+
+        let config: Config = toml::from_str("words_per_minute = 0").unwrap();
+        assert!(matches!(
+            config.validate(),
+            Err(ConfigError::ZeroWordsPerMinute)
+        ));
+    }
+
+    #[test]
+    fn test_apply_env() {
+        let env = |pairs: &[(&str, &str)]| -> Vec<(String, String)> {
+            pairs
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect()
+        };
+
+        let mut config = Config::default();
+        config.apply_env(env(&[("DEBUG", "1")])).unwrap();
+        assert!(!config.debug);
+        config
+            .apply_env(env(&[("WEBSITE_DEBUG", "true"), ("HTTP_PORT", "8080")]))
+            .unwrap();
+        assert!(config.debug);
+        assert_eq!(config.http_port, 8080);
+        config
+            .apply_env(env(&[("WEBSITE_DEBUG", "false")]))
+            .unwrap();
+        assert!(!config.debug);
+
+        assert!(matches!(
+            config.apply_env(env(&[("WEBSITE_DEBUG", "0")])),
+            Err(ConfigError::InvalidEnv { .. })
+        ));
+        assert!(config.apply_env(env(&[("HTTP_PORT", "http")])).is_err());
+        for value in ["false", "0", ""] {
+            let mut config = Config::default();
+            let res = config.apply_env(env(&[("RENDER_WIP", value)]));
+            assert!(!config.render_wip, "{:?}", value);
+            assert_eq!(res.is_ok(), value == "false", "{:?}", value);
+        }
+        config.apply_env(env(&[("RENDER_WIP", "true")])).unwrap();
+        assert!(config.render_wip);
+        config
+            .apply_env(env(&[("HSTS_INCLUDE_SUBDOMAINS", "true")]))
+            .unwrap();
+        assert!(config.hsts_include_subdomains);
+        assert!(config
+            .apply_env(env(&[("HSTS_INCLUDE_SUBDOMAINS", "yes")]))
+            .is_err());
+    }
+}
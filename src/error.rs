@@ -0,0 +1,98 @@
+//! Errors returned by the handlers, actix turns them into an html page with
+//! the matching status code
+
+use crate::config;
+use crate::md_ex::HeaderError;
+
+use actix_web::http::StatusCode;
+use actix_web::{HttpResponse, ResponseError};
+
+use std::io;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+#[derive(Debug)]
+pub enum AppError {
+    NotFound,
+    BadRequest(String),
+    Internal(Box<dyn std::error::Error>),
+}
+
+impl std::fmt::Display for AppError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AppError::NotFound => f.write_str("Not found"),
+            AppError::BadRequest(reason) => f.write_str(&format!("Bad request: {}", reason)),
+            AppError::Internal(e) => f.write_str(&format!("Internal error: {}", e)),
+        }
+    }
+}
+
+impl std::error::Error for AppError {}
+
+impl From<io::Error> for AppError {
+    fn from(e: io::Error) -> Self {
+        AppError::Internal(Box::new(e))
+    }
+}
+
+impl From<HeaderError> for AppError {
+    fn from(e: HeaderError) -> Self {
+        AppError::Internal(Box::new(e))
+    }
+}
+
+impl ResponseError for AppError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            AppError::NotFound => StatusCode::NOT_FOUND,
+            AppError::BadRequest(_) => StatusCode::BAD_REQUEST,
+            AppError::Internal(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        // the error is logged along with the request id by the logging
+        // middleware, visitors only get the details in debug mode
+        let details = config::get().debug.then(|| format!("{:?}", self));
+        HttpResponse::build(self.status_code())
+            .content_type(mime::TEXT_HTML)
+            .body(crate::error_page(self.status_code(), details))
+    }
+}
+
+/// identifies a request in the logs, sent back in the `X-Request-Id` header
+pub fn request_id() -> String {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+
+    let mut bytes = [0u8; 4];
+    // the counter keeps ids unique within a run, the random part across restarts
+    let _ = openssl::rand::rand_bytes(&mut bytes);
+    format!(
+        "{:08x}-{:x}",
+        u32::from_be_bytes(bytes),
+        COUNTER.fetch_add(1, Ordering::Relaxed)
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_status_code() {
+        assert_eq!(AppError::NotFound.status_code(), StatusCode::NOT_FOUND);
+        assert_eq!(
+            AppError::BadRequest(String::new()).status_code(),
+            StatusCode::BAD_REQUEST
+        );
+        assert_eq!(
+            AppError::from(io::Error::other("oops")).status_code(),
+            StatusCode::INTERNAL_SERVER_ERROR
+        );
+    }
+
+    #[test]
+    fn test_request_id() {
+        assert_ne!(request_id(), request_id());
+    }
+}
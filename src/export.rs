@@ -0,0 +1,359 @@
+//! Renders the whole website to plain files so it can be hosted without the
+//! server, every route is written to `<route>/index.html`
+
+use crate::article_index::Articles;
+use crate::config::Config;
+use crate::{config, feed, highlight, md_ex, pages, precompressed, redirects, sitemap};
+
+use actix_web::http::header::ContentEncoding;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::error::Error;
+use std::fs;
+use std::io::{self, Write};
+use std::path::Path;
+
+/// extensions of the files worth compressing
+const COMPRESSIBLE: [&str; 8] = ["html", "css", "js", "json", "xml", "svg", "txt", "md"];
+
+/// routes only the server can answer, there is no file to write for them
+const DYNAMIC_ROUTES: [&str; 1] = ["/search"];
+
+/// leaves the links to `DYNAMIC_ROUTES` out of the nav, has to be called
+/// before the configuration is initialized
+pub fn adjust_config(config: &mut Config) {
+    config.nav.retain(|link| {
+        let path = link.url.split(['?', '#']).next().unwrap_or_default();
+        !DYNAMIC_ROUTES.contains(&md_ex::normalize_path(path).as_str())
+    });
+}
+
+/// marks a directory written by `build`, only those are replaced
+const MARKER: &str = ".website-export";
+
+/// the export is written next to `out` then takes its place, nothing of a
+/// previous export (stubs, pages of deleted articles) lingers
+pub fn build(out: &Path) -> Result<(), Box<dyn Error>> {
+    let name = out
+        .file_name()
+        .ok_or_else(|| format!("'{}' has to name a directory", out.display()))?;
+    if out.exists() && !out.join(MARKER).exists() && fs::read_dir(out)?.next().is_some() {
+        return Err(format!(
+            "Refusing to replace '{}', it isn't an export, remove it first",
+            out.display()
+        )
+        .into());
+    }
+
+    let mut staging_name = name.to_os_string();
+    staging_name.push(".partial");
+    let staging = out.with_file_name(staging_name);
+    if staging.exists() {
+        fs::remove_dir_all(&staging)?;
+    }
+    fs::create_dir_all(&staging)?;
+
+    let exported = match export(&staging) {
+        Ok(v) => v,
+        Err(e) => {
+            let _ = fs::remove_dir_all(&staging);
+            return Err(e);
+        }
+    };
+    if out.exists() {
+        fs::remove_dir_all(out)?;
+    }
+    fs::rename(&staging, out)?;
+
+    log::info!("Exported {} articles to '{}'", exported, out.display());
+    Ok(())
+}
+
+/// the number of articles exported
+fn export(out: &Path) -> Result<usize, Box<dyn Error>> {
+    write_file(out, MARKER, "")?;
+    let articles_ = Articles::load(
+        Path::new(config::get().fs_articles_path.as_str()),
+        config::get().render_wip,
+    )?;
+    let posts = articles_.posts.as_slice();
+
+    write_page(out, "", &crate::index_page(posts)?)?;
+    write_file(out, "404.html", &crate::not_found_page())?;
+    for page in pages::get().iter() {
+        write_page(out, &page.slug, &crate::md_page(&page.path)?)?;
+    }
+
+    for post in posts.iter() {
+        write_page(
+            out,
+            &format!("article/{}", post.slug),
+            &crate::article_page(post, articles_.body(post)),
+        )?;
+    }
+
+    write_page(
+        out,
+        "articles",
+        &crate::articles_list_page("Articles", posts, 0, "articles"),
+    )?;
+    for page in 0..=crate::last_page(posts) {
+        write_page(
+            out,
+            &format!("articles/page/{}", page),
+            &crate::articles_list_page("Articles", posts, page, "articles"),
+        )?;
+    }
+
+    write_page(out, "tags", &crate::tags_page(posts))?;
+    for (slug, (name, count)) in crate::tag_counts(posts) {
+        if let Some(page) = crate::tagged_articles_page(posts, &slug, 0) {
+            write_page(out, &format!("tags/{}", slug), &page)?;
+        }
+        for p in 0..=count / crate::ARTICLES_PER_PAGE {
+            if let Some(page) = crate::tagged_articles_page(posts, &slug, p) {
+                write_page(out, &format!("tags/{}/page/{}", slug, p), &page)?;
+            }
+        }
+        let channel = crate::feed_channel(feed::Filter::Tag(slug.clone()), &format!("#{}", name));
+        write_feeds(out, &format!("tags/{}/", slug), &channel, &articles_)?;
+    }
+
+    let authors: BTreeSet<String> = posts
+        .iter()
+        .filter_map(|post| post.author.as_deref())
+        .map(md_ex::slugify)
+        .collect();
+    for slug in authors {
+        let name = crate::author_name(posts, &slug).unwrap_or_default();
+        let channel = crate::feed_channel(feed::Filter::Author(slug.clone()), name);
+        write_feeds(out, &format!("authors/{}/", slug), &channel, &articles_)?;
+    }
+
+    let channel = crate::feed_channel(feed::Filter::All, "");
+    write_feeds(out, "", &channel, &articles_)?;
+
+    // by source, the last one wins
+    let mut redirects: BTreeMap<String, String> = posts
+        .iter()
+        .map(|post| {
+            (
+                format!("/article/{}", post.file_name),
+                format!("/article/{}", post.slug),
+            )
+        })
+        .collect();
+    redirects.extend(
+        articles_
+            .aliases()
+            .map(|(from, slug)| (from.to_string(), format!("/article/{}", slug))),
+    );
+    // the redirects file takes precedence over the aliases
+    redirects.extend(
+        redirects::get()
+            .iter()
+            .map(|(from, to)| (from.to_string(), to.to_string())),
+    );
+    for (from, to) in redirects {
+        write_redirect(out, &from, &to)?;
+    }
+
+    write_file(
+        out,
+        "sitemap.xml",
+        &sitemap::build(&config::get().base_url, &articles_, pages::get())?,
+    )?;
+    write_file(out, "highlight.css", highlight::theme_css())?;
+
+    copy_dir(
+        Path::new(config::get().fs_data_path.as_str()),
+        &out.join("data"),
+    )?;
+    copy_dir(
+        Path::new(config::get().fs_media_path.as_str()),
+        &out.join("media"),
+    )?;
+
+    precompress_dir(out)?;
+
+    Ok(posts.len())
+}
+
+/// `prefix` is either empty or ends with a `/`
+fn write_feeds(
+    out: &Path,
+    prefix: &str,
+    channel: &feed::Channel,
+    articles_: &Articles,
+) -> io::Result<()> {
+    for format in [feed::Format::Rss, feed::Format::Atom, feed::Format::Json] {
+        let name = format!("{}{}", prefix, format.file_name());
+        write_file(out, &name, &feed::build(format, channel, articles_)?)?;
+    }
+    // readers subscribed to `rss` before it had an extension, they don't
+    // follow the refresh of a redirect page
+    write_file(
+        out,
+        &format!("{}rss", prefix),
+        &feed::build(feed::Format::Rss, channel, articles_)?,
+    )
+}
+
+/// static hosts can't answer with a 301, `from` gets a page that sends
+/// browsers to `to` instead, unless a page was already written there
+fn write_redirect(out: &Path, from: &str, to: &str) -> io::Result<()> {
+    let from = from.trim_start_matches('/');
+    let name = if from.ends_with(".html") {
+        from.to_string()
+    } else {
+        format!("{}/index.html", from)
+    };
+    if from.is_empty() || from.split('/').any(|segment| segment == "..") {
+        log::warn!("Skipping redirect from '/{}' to '{}'", from, to);
+        return Ok(());
+    }
+    if out.join(&name).exists() {
+        log::warn!("Skipping redirect from '/{}', the page exists", from);
+        return Ok(());
+    }
+    // written by hand, `http-equiv` isn't an identifier the html macro accepts
+    let body = format!(
+        "<!DOCTYPE html><html><head><meta charset=\"UTF-8\"><title>Redirecting</title>\
+         <link rel=\"canonical\" href=\"{to}\">\
+         <meta http-equiv=\"refresh\" content=\"0; url={to}\"></head>\
+         <body><a href=\"{to}\">This page has moved</a></body></html>"
+    );
+
+    write_file(out, &name, &body)
+}
+
+fn write_page(out: &Path, route: &str, content: &str) -> io::Result<()> {
+    let name = if route.is_empty() {
+        "index.html".to_string()
+    } else {
+        format!("{}/index.html", route)
+    };
+    write_file(out, &name, content)
+}
+
+fn write_file(out: &Path, name: &str, content: &str) -> io::Result<()> {
+    let path = out.join(name);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, content)
+}
+
+fn copy_dir(from: &Path, to: &Path) -> io::Result<()> {
+    fs::create_dir_all(to)?;
+    for entry in fs::read_dir(from)? {
+        let entry = entry?;
+        let target = to.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            copy_dir(&entry.path(), &target)?;
+        } else {
+            fs::copy(entry.path(), target)?;
+        }
+    }
+    Ok(())
+}
+
+/// writes a `.br` and a `.gz` sibling next to every text file, servers able
+/// to pick them send those instead of compressing the file on every request
+fn precompress_dir(dir: &Path) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let path = entry.path();
+        if entry.file_type()?.is_dir() {
+            precompress_dir(&path)?;
+            continue;
+        }
+
+        let compressible = path
+            .extension()
+            .is_some_and(|ext| COMPRESSIBLE.iter().any(|v| ext.eq_ignore_ascii_case(v)));
+        if !compressible {
+            continue;
+        }
+
+        let content = fs::read(&path)?;
+        for (ext, encoding) in precompressed::ENCODINGS {
+            let compressed = match encoding {
+                ContentEncoding::Brotli => compress_brotli(&content)?,
+                _ => compress_gzip(&content)?,
+            };
+            fs::write(precompressed::sibling(&path, ext), compressed)?;
+        }
+    }
+    Ok(())
+}
+
+fn compress_gzip(content: &[u8]) -> io::Result<Vec<u8>> {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::best());
+    encoder.write_all(content)?;
+    encoder.finish()
+}
+
+fn compress_brotli(content: &[u8]) -> io::Result<Vec<u8>> {
+    let mut compressed = Vec::new();
+    {
+        // quality 11 and a 4MiB window, the highest settings
+        let mut writer = brotli::CompressorWriter::new(&mut compressed, 4096, 11, 22);
+        writer.write_all(content)?;
+    }
+    Ok(compressed)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use std::io::Read;
+
+    #[test]
+    fn test_adjust_config() {
+        let mut config = Config::default();
+        assert!(config.nav.iter().any(|link| link.url == "/search"));
+        let links = config.nav.len();
+
+        adjust_config(&mut config);
+        assert!(config.nav.iter().all(|link| link.url != "/search"));
+        assert_eq!(config.nav.len(), links - 1);
+    }
+
+    #[test]
+    fn test_precompress_dir() {
+        let dir = std::env::temp_dir().join(format!("precompress_dir_{}", std::process::id()));
+        fs::create_dir_all(dir.join("css")).unwrap();
+        let page = "<p>hello</p>".repeat(100);
+        fs::write(dir.join("index.html"), &page).unwrap();
+        fs::write(dir.join("css/site.CSS"), "body {}").unwrap();
+        fs::write(dir.join("image.png"), "not text").unwrap();
+        fs::write(dir.join("rss"), "no extension").unwrap();
+
+        precompress_dir(&dir).unwrap();
+
+        let mut decoded = String::new();
+        flate2::read::GzDecoder::new(fs::File::open(dir.join("index.html.gz")).unwrap())
+            .read_to_string(&mut decoded)
+            .unwrap();
+        assert_eq!(decoded, page);
+        let mut decoded = String::new();
+        brotli::Decompressor::new(fs::File::open(dir.join("index.html.br")).unwrap(), 4096)
+            .read_to_string(&mut decoded)
+            .unwrap();
+        assert_eq!(decoded, page);
+
+        assert!(dir.join("css/site.CSS.br").exists());
+        assert!(dir.join("css/site.CSS.gz").exists());
+        assert!(!dir.join("image.png.br").exists());
+        assert!(!dir.join("image.png.gz").exists());
+        assert!(!dir.join("rss.gz").exists());
+        // compressed files are not compressed again
+        assert!(!dir.join("index.html.gz.gz").exists());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,529 @@
+use crate::article_index::Articles;
+use crate::post::Article;
+
+use quick_xml::events::{BytesDecl, BytesText, Event};
+use quick_xml::Writer;
+use serde::Serialize;
+
+use std::io;
+
+use time::format_description::well_known::{Rfc2822, Rfc3339};
+use time::{OffsetDateTime, UtcOffset};
+
+/// advertised to readers through `<ttl>`
+pub const TTL: u32 = 1800;
+
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub enum Format {
+    Rss,
+    Atom,
+    Json,
+}
+
+impl Format {
+    /// the last segment of the url a feed is served at, `rss` is what the
+    /// RSS feed was served at before it had an extension
+    pub fn from_file_name(name: &str) -> Option<Self> {
+        match name {
+            "rss" | "rss.xml" => Some(Format::Rss),
+            "atom.xml" => Some(Format::Atom),
+            "feed.json" => Some(Format::Json),
+            _ => None,
+        }
+    }
+
+    pub fn file_name(&self) -> &'static str {
+        match self {
+            Format::Rss => "rss.xml",
+            Format::Atom => "atom.xml",
+            Format::Json => "feed.json",
+        }
+    }
+
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            Format::Rss => "application/rss+xml; charset=utf-8",
+            Format::Atom => "application/atom+xml; charset=utf-8",
+            Format::Json => "application/feed+json; charset=utf-8",
+        }
+    }
+}
+
+/// which articles end up in a feed, tags and authors are matched by slug
+pub enum Filter {
+    All,
+    Tag(String),
+    Author(String),
+}
+
+impl Filter {
+    pub fn matches(&self, post: &Article) -> bool {
+        match self {
+            Filter::All => true,
+            Filter::Tag(slug) => post.has_tag(slug),
+            Filter::Author(slug) => post.has_author(slug),
+        }
+    }
+}
+
+/// describes the feed itself, as opposed to its entries
+pub struct Channel<'a> {
+    pub title: String,
+    pub description: String,
+    /// root of the website, without a trailing slash
+    pub base_url: &'a str,
+    /// html page the feed mirrors
+    pub link: String,
+    /// where the feeds are served, `""` for the site wide ones
+    pub path: String,
+    pub copyright: String,
+    /// used when an article doesn't name its author
+    pub author: &'a str,
+    pub words_per_minute: u32,
+    pub filter: Filter,
+}
+
+impl Channel<'_> {
+    pub fn article_url(&self, post: &Article) -> String {
+        format!("{}/article/{}", self.base_url, post.slug)
+    }
+
+    /// ids predate slugs, they still use the file name so readers don't list
+    /// every article again
+    fn article_id(&self, post: &Article) -> String {
+        format!("{}/article/{}", self.base_url, post.file_name)
+    }
+
+    fn feed_url(&self, format: Format) -> String {
+        format!("{}{}/{}", self.base_url, self.path, format.file_name())
+    }
+
+    /// the article's html preceded by its reading time
+    fn content(&self, post: &Article, body: &str) -> String {
+        format!(
+            "<p><em>{} words, {} min read</em></p>{}",
+            post.word_count,
+            post.reading_minutes(self.words_per_minute),
+            body
+        )
+    }
+
+    /// most recent revision of the articles of the feed
+    fn updated(&self, articles: &Articles) -> Option<OffsetDateTime> {
+        self.posts(articles).map(|post| post.updated).max()
+    }
+
+    pub fn posts<'b>(&'b self, articles: &'b Articles) -> impl Iterator<Item = &'b Article> {
+        articles
+            .posts
+            .iter()
+            .filter(|post| self.filter.matches(post))
+    }
+}
+
+pub fn build(format: Format, channel: &Channel, articles: &Articles) -> io::Result<String> {
+    match format {
+        Format::Rss => rss(channel, articles),
+        Format::Atom => atom(channel, articles),
+        Format::Json => json(channel, articles),
+    }
+}
+
+/// RFC 822 date in UTC, as required by RSS 2.0
+fn rfc822(date: OffsetDateTime) -> io::Result<String> {
+    date.to_offset(UtcOffset::UTC)
+        .format(&Rfc2822)
+        .map_err(io::Error::other)
+}
+
+/// RFC 3339 date in UTC, used by Atom, JSON Feed and the sitemap
+pub fn rfc3339(date: OffsetDateTime) -> io::Result<String> {
+    date.to_offset(UtcOffset::UTC)
+        .format(&Rfc3339)
+        .map_err(io::Error::other)
+}
+
+fn text_element<W: io::Write>(writer: &mut Writer<W>, name: &str, text: &str) -> io::Result<()> {
+    writer
+        .create_element(name)
+        .write_text_content(BytesText::new(text))?;
+    Ok(())
+}
+
+pub fn rss(channel: &Channel, articles: &Articles) -> io::Result<String> {
+    let mut writer = Writer::new_with_indent(Vec::new(), b' ', 2);
+    writer.write_event(Event::Decl(BytesDecl::new("1.0", Some("UTF-8"), None)))?;
+
+    writer
+        .create_element("rss")
+        .with_attribute(("version", "2.0"))
+        // authors are names, not the email addresses <author> expects
+        .with_attribute(("xmlns:dc", "http://purl.org/dc/elements/1.1/"))
+        .write_inner_content(|writer| {
+            writer
+                .create_element("channel")
+                .write_inner_content(|writer| {
+                    text_element(writer, "title", &channel.title)?;
+                    text_element(writer, "description", &channel.description)?;
+                    text_element(writer, "link", &channel.link)?;
+                    text_element(writer, "copyright", &channel.copyright)?;
+                    if let Some(post) = channel.posts(articles).next() {
+                        text_element(writer, "pubDate", &rfc822(post.date)?)?;
+                    }
+                    if let Some(updated) = channel.updated(articles) {
+                        text_element(writer, "lastBuildDate", &rfc822(updated)?)?;
+                    }
+                    text_element(writer, "ttl", &TTL.to_string())?;
+
+                    for post in channel.posts(articles) {
+                        rss_item(writer, channel, post, articles.body(post))?;
+                    }
+                    Ok(())
+                })?;
+            Ok(())
+        })?;
+
+    String::from_utf8(writer.into_inner()).map_err(io::Error::other)
+}
+
+fn rss_item<W: io::Write>(
+    writer: &mut Writer<W>,
+    channel: &Channel,
+    post: &Article,
+    body: &str,
+) -> io::Result<()> {
+    let url = channel.article_url(post);
+    let title = match &post.blurb {
+        Some(blurb) => format!("{} – {}", post.title, blurb),
+        None => post.title.clone(),
+    };
+
+    writer
+        .create_element("item")
+        .write_inner_content(|writer| {
+            text_element(writer, "title", &title)?;
+            text_element(writer, "link", &url)?;
+            writer
+                .create_element("guid")
+                .with_attribute(("isPermaLink", "false"))
+                .write_text_content(BytesText::new(&channel.article_id(post)))?;
+            text_element(writer, "pubDate", &rfc822(post.date)?)?;
+            if let Some(author) = &post.author {
+                text_element(writer, "dc:creator", author)?;
+            }
+            for tag in post.tags.iter() {
+                text_element(writer, "category", tag)?;
+            }
+            text_element(writer, "description", &channel.content(post, body))?;
+            Ok(())
+        })?;
+    Ok(())
+}
+
+pub fn atom(channel: &Channel, articles: &Articles) -> io::Result<String> {
+    let mut writer = Writer::new_with_indent(Vec::new(), b' ', 2);
+    writer.write_event(Event::Decl(BytesDecl::new("1.0", Some("UTF-8"), None)))?;
+
+    let updated = channel
+        .updated(articles)
+        .unwrap_or(OffsetDateTime::UNIX_EPOCH);
+
+    writer
+        .create_element("feed")
+        .with_attribute(("xmlns", "http://www.w3.org/2005/Atom"))
+        .write_inner_content(|writer| {
+            let self_url = channel.feed_url(Format::Atom);
+            text_element(writer, "title", &channel.title)?;
+            text_element(writer, "subtitle", &channel.description)?;
+            text_element(writer, "id", &self_url)?;
+            writer
+                .create_element("link")
+                .with_attribute(("rel", "alternate"))
+                .with_attribute(("href", channel.link.as_str()))
+                .write_empty()?;
+            writer
+                .create_element("link")
+                .with_attribute(("rel", "self"))
+                .with_attribute(("href", self_url.as_str()))
+                .write_empty()?;
+            text_element(writer, "updated", &rfc3339(updated)?)?;
+            writer
+                .create_element("author")
+                .write_inner_content(|writer| text_element(writer, "name", channel.author))?;
+            text_element(writer, "rights", &channel.copyright)?;
+
+            for post in channel.posts(articles) {
+                atom_entry(writer, channel, post, articles.body(post))?;
+            }
+            Ok(())
+        })?;
+
+    String::from_utf8(writer.into_inner()).map_err(io::Error::other)
+}
+
+fn atom_entry<W: io::Write>(
+    writer: &mut Writer<W>,
+    channel: &Channel,
+    post: &Article,
+    body: &str,
+) -> io::Result<()> {
+    let url = channel.article_url(post);
+
+    writer
+        .create_element("entry")
+        .write_inner_content(|writer| {
+            text_element(writer, "title", &post.title)?;
+            text_element(writer, "id", &channel.article_id(post))?;
+            writer
+                .create_element("link")
+                .with_attribute(("rel", "alternate"))
+                .with_attribute(("href", url.as_str()))
+                .write_empty()?;
+            text_element(writer, "published", &rfc3339(post.date)?)?;
+            text_element(writer, "updated", &rfc3339(post.updated)?)?;
+            if let Some(author) = &post.author {
+                writer
+                    .create_element("author")
+                    .write_inner_content(|writer| text_element(writer, "name", author))?;
+            }
+            for tag in post.tags.iter() {
+                writer
+                    .create_element("category")
+                    .with_attribute(("term", tag.as_str()))
+                    .write_empty()?;
+            }
+            if let Some(blurb) = &post.blurb {
+                text_element(writer, "summary", blurb)?;
+            }
+            writer
+                .create_element("content")
+                .with_attribute(("type", "html"))
+                .write_text_content(BytesText::new(&channel.content(post, body)))?;
+            Ok(())
+        })?;
+    Ok(())
+}
+
+/// https://www.jsonfeed.org/version/1.1/
+#[derive(Serialize)]
+struct JsonFeed<'a> {
+    version: &'static str,
+    title: &'a str,
+    description: &'a str,
+    home_page_url: String,
+    feed_url: String,
+    authors: Vec<JsonAuthor<'a>>,
+    items: Vec<JsonItem<'a>>,
+}
+
+#[derive(Serialize)]
+struct JsonAuthor<'a> {
+    name: &'a str,
+}
+
+#[derive(Serialize)]
+struct JsonItem<'a> {
+    id: String,
+    url: String,
+    title: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    summary: Option<&'a str>,
+    content_html: String,
+    date_published: String,
+    date_modified: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    authors: Vec<JsonAuthor<'a>>,
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    tags: &'a [String],
+}
+
+pub fn json(channel: &Channel, articles: &Articles) -> io::Result<String> {
+    let items = channel
+        .posts(articles)
+        .map(|post| {
+            let url = channel.article_url(post);
+            Ok(JsonItem {
+                id: channel.article_id(post),
+                url,
+                title: &post.title,
+                summary: post.blurb.as_deref(),
+                content_html: channel.content(post, articles.body(post)),
+                date_published: rfc3339(post.date)?,
+                date_modified: rfc3339(post.updated)?,
+                authors: post.author.iter().map(|name| JsonAuthor { name }).collect(),
+                tags: &post.tags,
+            })
+        })
+        .collect::<io::Result<Vec<_>>>()?;
+
+    let feed = JsonFeed {
+        version: "https://jsonfeed.org/version/1.1",
+        title: &channel.title,
+        description: &channel.description,
+        home_page_url: channel.link.clone(),
+        feed_url: channel.feed_url(Format::Json),
+        authors: vec![JsonAuthor {
+            name: channel.author,
+        }],
+        items,
+    };
+
+    serde_json::to_string_pretty(&feed).map_err(io::Error::other)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use std::path::PathBuf;
+
+    /// two articles read from `<tmp>/<name>`, the caller removes it
+    fn articles(name: &str) -> (PathBuf, Articles) {
+        let dir = std::env::temp_dir().join(format!("{}_{}", name, std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(
+            dir.join("hello.md"),
+            "---\nTitle: Fish & <Chips>\nDate: 2023-04-12 09:30 +02:00\n\
+             Updated: 2023-05-01\nAuthor: Jane Doe\nTags: Rust, Web\n---\n\
+             # Fish\n\nSome *text*.\n",
+        )
+        .unwrap();
+        std::fs::write(
+            dir.join("other.md"),
+            "---\nTitle: Other\nDate: 2023-01-01\nUpdated: 2023-01-01\nTags: C\n---\nMore text.\n",
+        )
+        .unwrap();
+        let (articles, errors) = Articles::scan(&dir, false).unwrap();
+        assert!(errors.is_empty());
+        (dir, articles)
+    }
+
+    fn channel(filter: Filter) -> Channel<'static> {
+        Channel {
+            title: "Blog".to_string(),
+            description: "A blog".to_string(),
+            base_url: "https://example.com",
+            link: "https://example.com/".to_string(),
+            path: String::new(),
+            copyright: "copyright someone".to_string(),
+            author: "Someone",
+            words_per_minute: 200,
+            filter,
+        }
+    }
+
+    #[test]
+    fn test_rss() {
+        let (dir, articles) = articles("feed_rss");
+        let xml = build(Format::Rss, &channel(Filter::All), &articles).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert!(xml.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>"));
+        assert_eq!(xml.matches("<item>").count(), 2);
+        assert!(xml.contains("<title>Fish &amp; &lt;Chips&gt;</title>"));
+        assert!(
+            xml.contains("<guid isPermaLink=\"false\">https://example.com/article/hello.md</guid>")
+        );
+        assert!(xml.contains("<link>https://example.com/article/hello</link>"));
+        assert!(xml.contains("<pubDate>Wed, 12 Apr 2023 07:30:00 +0000</pubDate>"));
+        assert!(xml.contains("<pubDate>Sun, 01 Jan 2023 00:00:00 +0000</pubDate>"));
+        assert!(xml.contains("<dc:creator>Jane Doe</dc:creator>"));
+        assert!(xml.contains("<category>Rust</category>"));
+        assert!(xml.contains("<category>Web</category>"));
+        assert!(xml.contains("<lastBuildDate>Mon, 01 May 2023 00:00:00 +0000</lastBuildDate>"));
+        // only the body, escaped
+        assert!(xml.contains("&lt;em&gt;text&lt;/em&gt;"));
+        assert!(!xml.contains("DOCTYPE"));
+        assert!(!xml.contains("<em>"));
+    }
+
+    #[test]
+    fn test_atom() {
+        let (dir, articles) = articles("feed_atom");
+        let xml = build(Format::Atom, &channel(Filter::All), &articles).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert!(xml.contains("<feed xmlns=\"http://www.w3.org/2005/Atom\">"));
+        assert!(xml.contains("<id>https://example.com/atom.xml</id>"));
+        assert!(xml.contains("<link rel=\"self\" href=\"https://example.com/atom.xml\"/>"));
+        assert_eq!(xml.matches("<entry>").count(), 2);
+        // the feed is as recent as its latest revision
+        assert_eq!(
+            xml.matches("<updated>2023-05-01T00:00:00Z</updated>")
+                .count(),
+            2
+        );
+        assert!(xml.contains("<published>2023-04-12T07:30:00Z</published>"));
+        assert!(xml.contains("<title>Fish &amp; &lt;Chips&gt;</title>"));
+        assert!(xml.contains("<category term=\"Rust\"/>"));
+        assert!(xml.contains("<content type=\"html\">"));
+        assert!(xml.contains("&lt;em&gt;text&lt;/em&gt;"));
+    }
+
+    #[test]
+    fn test_json() {
+        let (dir, articles) = articles("feed_json");
+        let json = build(Format::Json, &channel(Filter::All), &articles).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        let feed: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(feed["version"], "https://jsonfeed.org/version/1.1");
+        assert_eq!(feed["feed_url"], "https://example.com/feed.json");
+        assert_eq!(feed["home_page_url"], "https://example.com/");
+        assert_eq!(feed["authors"][0]["name"], "Someone");
+
+        let item = &feed["items"][0];
+        assert_eq!(item["id"], "https://example.com/article/hello.md");
+        assert_eq!(item["url"], "https://example.com/article/hello");
+        assert_eq!(item["title"], "Fish & <Chips>");
+        assert_eq!(item["date_published"], "2023-04-12T07:30:00Z");
+        assert_eq!(item["date_modified"], "2023-05-01T00:00:00Z");
+        assert_eq!(item["authors"][0]["name"], "Jane Doe");
+        assert_eq!(item["tags"], serde_json::json!(["Rust", "Web"]));
+        assert!(item["content_html"]
+            .as_str()
+            .unwrap()
+            .contains("<em>text</em>"));
+        // optional fields are left out rather than null
+        assert!(feed["items"][1].get("authors").is_none());
+        assert!(feed["items"][1].get("summary").is_none());
+    }
+
+    #[test]
+    fn test_filter() {
+        let (dir, articles) = articles("feed_filter");
+        std::fs::remove_dir_all(&dir).unwrap();
+        let slugs = |filter: Filter| -> Vec<String> {
+            channel(filter)
+                .posts(&articles)
+                .map(|post| post.slug.clone())
+                .collect()
+        };
+
+        assert_eq!(slugs(Filter::All), ["hello", "other"]);
+        assert_eq!(slugs(Filter::Tag("rust".to_string())), ["hello"]);
+        assert_eq!(slugs(Filter::Tag("c".to_string())), ["other"]);
+        assert!(slugs(Filter::Tag("go".to_string())).is_empty());
+        assert_eq!(slugs(Filter::Author("jane-doe".to_string())), ["hello"]);
+        assert!(slugs(Filter::Author("someone".to_string())).is_empty());
+
+        let mut tagged = channel(Filter::Tag("c".to_string()));
+        tagged.path = "/tags/c".to_string();
+        let xml = build(Format::Atom, &tagged, &articles).unwrap();
+        assert_eq!(xml.matches("<entry>").count(), 1);
+        assert!(xml.contains("<id>https://example.com/tags/c/atom.xml</id>"));
+        assert!(xml.contains("<updated>2023-01-01T00:00:00Z</updated>"));
+        let xml = build(Format::Rss, &tagged, &articles).unwrap();
+        assert_eq!(xml.matches("<item>").count(), 1);
+        assert!(!xml.contains("Chips"));
+    }
+
+    #[test]
+    fn test_rfc822() {
+        let date = time::macros::datetime!(2023-04-12 0:00 UTC);
+        assert_eq!(rfc822(date).unwrap(), "Wed, 12 Apr 2023 00:00:00 +0000");
+        assert_eq!(rfc3339(date).unwrap(), "2023-04-12T00:00:00Z");
+        let date = time::macros::datetime!(2023-04-12 9:30 +2);
+        assert_eq!(rfc3339(date).unwrap(), "2023-04-12T07:30:00Z");
+    }
+}
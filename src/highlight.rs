@@ -0,0 +1,151 @@
+//! Server side syntax highlighting of the fenced code blocks, the code is
+//! wrapped in spans whose classes are styled by the theme css served at
+//! `/highlight.css`
+
+use crate::md_ex;
+
+use syntect::html::{css_for_theme_with_class_style, ClassStyle, ClassedHTMLGenerator};
+use syntect::parsing::SyntaxSet;
+use syntect::util::LinesWithEndings;
+use two_face::theme::EmbeddedThemeName;
+
+use std::sync::OnceLock;
+
+const THEME: EmbeddedThemeName = EmbeddedThemeName::Nord;
+
+/// keeps the theme's classes from clashing with the ones of the site
+const CLASS_STYLE: ClassStyle = ClassStyle::SpacedPrefixed { prefix: "hl-" };
+
+/// fence languages highlight.js knew under another name than syntect, the
+/// articles were written for it
+const ALIASES: [(&str, &str); 13] = [
+    ("x86asm", "asm"),
+    ("x86", "asm"),
+    ("shell", "bash"),
+    ("console", "bash"),
+    ("csharp", "cs"),
+    ("fsharp", "f#"),
+    ("dos", "bat"),
+    ("protobuf", "proto"),
+    ("fortran", "f90"),
+    ("apache", "htaccess"),
+    ("scheme", "lisp"),
+    ("delphi", "pas"),
+    ("objectivec", "objective-c"),
+];
+
+const CODE_START: &str = "<pre><code class=\"language-";
+const CODE_END: &str = "</code></pre>";
+
+fn syntaxes() -> &'static SyntaxSet {
+    static SYNTAXES: OnceLock<SyntaxSet> = OnceLock::new();
+    SYNTAXES.get_or_init(two_face::syntax::extra_newlines)
+}
+
+/// the stylesheet of the theme, generated once
+pub fn theme_css() -> &'static str {
+    static CSS: OnceLock<String> = OnceLock::new();
+    CSS.get_or_init(|| {
+        css_for_theme_with_class_style(two_face::theme::extra().get(THEME), CLASS_STYLE)
+            .unwrap_or_else(|e| {
+                log::error!("Unable to generate the highlighting css: {e}");
+                String::new()
+            })
+    })
+}
+
+/// highlights the `<pre><code class="language-*">` blocks of the markdown's
+/// html, blocks without a language or in an unknown one are left untouched
+pub fn highlight_code_blocks(html: &str) -> String {
+    let mut out = String::with_capacity(html.len());
+    let mut rest = html;
+
+    while let Some(start) = rest.find(CODE_START) {
+        out.push_str(&rest[..start]);
+        let block = &rest[start..];
+
+        let highlighted = block
+            .find(CODE_END)
+            .and_then(|end| Some((end, highlight_block(&block[CODE_START.len()..end])?)));
+        match highlighted {
+            Some((end, v)) => {
+                out.push_str(&v);
+                rest = &block[end + CODE_END.len()..];
+            }
+            None => {
+                out.push_str(CODE_START);
+                rest = &block[CODE_START.len()..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// `block` is what follows `<pre><code class="language-` up to `</code></pre>`
+fn highlight_block(block: &str) -> Option<String> {
+    let (lang, code) = block.split_once("\">")?;
+    let syntaxes = syntaxes();
+    let token = ALIASES
+        .iter()
+        .find(|(alias, _)| alias.eq_ignore_ascii_case(lang))
+        .map_or(lang, |(_, token)| token);
+    let syntax = syntaxes.find_syntax_by_token(token)?;
+
+    let code = md_ex::unescape_html(code);
+    let mut generator = ClassedHTMLGenerator::new_with_class_style(syntax, syntaxes, CLASS_STYLE);
+    for line in LinesWithEndings::from(&code) {
+        if let Err(e) = generator.parse_html_for_line_which_includes_newline(line) {
+            log::warn!("Unable to highlight a {lang} code block: {e}");
+            return None;
+        }
+    }
+
+    Some(format!(
+        "<pre><code class=\"language-{} hl-code\">{}</code></pre>",
+        lang,
+        generator.finalize()
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_highlight_code_blocks() {
+        let html = "<p>hi</p>\n<pre><code class=\"language-rust\">let a = &quot;&lt;&gt;&quot;;\n</code></pre>\n";
+        let highlighted = highlight_code_blocks(html);
+        assert!(highlighted.starts_with("<p>hi</p>\n<pre><code class=\"language-rust hl-code\">"));
+        assert!(highlighted.contains("<span class=\"hl-"));
+        assert!(highlighted.contains("&lt;&gt;"));
+        assert!(highlighted.ends_with("</code></pre>\n"));
+
+        let plain =
+            "<pre><code>a &lt; b</code></pre><pre><code class=\"language-nope\">x</code></pre>";
+        assert_eq!(highlight_code_blocks(plain), plain);
+
+        assert!(theme_css().contains(".hl-code"));
+    }
+
+    #[test]
+    fn test_aliases() {
+        for (alias, token) in ALIASES {
+            assert!(
+                syntaxes().find_syntax_by_token(alias).is_none(),
+                "{}",
+                alias
+            );
+            assert!(
+                syntaxes().find_syntax_by_token(token).is_some(),
+                "{}",
+                token
+            );
+        }
+
+        let html = "<pre><code class=\"language-x86asm\">mov ax, 0x07c0\n</code></pre>";
+        let highlighted = highlight_code_blocks(html);
+        assert!(highlighted.starts_with("<pre><code class=\"language-x86asm hl-code\">"));
+        assert!(highlighted.contains("<span class=\"hl-"));
+    }
+}
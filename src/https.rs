@@ -0,0 +1,97 @@
+//! When tls is configured, the plain http listener only answers the ACME
+//! http-01 challenges (files of `fs_acme_challenge_path`), every other request
+//! permanently moves to https. Responses sent over https carry a
+//! `Strict-Transport-Security` header, see `hsts_max_age`.
+
+use crate::config::{self, Config};
+
+use actix_web::http::header;
+use actix_web::{HttpRequest, HttpResponse};
+
+/// where certbot and friends expect the challenges to be served
+pub const ACME_CHALLENGE_PATH: &str = "/.well-known/acme-challenge";
+
+/// value of the header, `None` when it is disabled
+pub fn strict_transport_security(config: &Config) -> Option<String> {
+    if config.hsts_max_age == 0 {
+        return None;
+    }
+    let mut value = format!("max-age={}", config.hsts_max_age);
+    if config.hsts_include_subdomains {
+        value.push_str("; includeSubDomains");
+    }
+    Some(value)
+}
+
+/// default service of the http listener, the host comes from `base_url`: the
+/// `Host` header is up to the client and would redirect anywhere
+pub async fn redirect(req: HttpRequest) -> HttpResponse {
+    let config = config::get();
+    let target = location(
+        host(&config.base_url),
+        config.https_port,
+        req.uri().path_and_query().map_or("/", |v| v.as_str()),
+    );
+    HttpResponse::MovedPermanently()
+        .insert_header((header::LOCATION, target))
+        .finish()
+}
+
+/// `https://example.com:8443/blog` -> `example.com:8443`
+fn host(base_url: &str) -> &str {
+    let rest = base_url
+        .split_once("://")
+        .map_or(base_url, |(_, rest)| rest);
+    rest.split(['/', '?', '#']).next().unwrap_or_default()
+}
+
+/// the same host and path over https, the port of the http listener is
+/// replaced by the https one
+fn location(host: &str, https_port: u16, path_and_query: &str) -> String {
+    // `[::1]:8080` or `example.com:8080`, but not `[::1]`
+    let host = match host.rsplit_once(':') {
+        Some((name, port)) if !port.is_empty() && port.chars().all(|c| c.is_ascii_digit()) => name,
+        _ => host,
+    };
+    match https_port {
+        443 => format!("https://{}{}", host, path_and_query),
+        port => format!("https://{}:{}{}", host, port, path_and_query),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_location() {
+        assert_eq!(
+            location("example.com", 443, "/article/foo?p=1"),
+            "https://example.com/article/foo?p=1"
+        );
+        assert_eq!(
+            location("example.com:8080", 8443, "/"),
+            "https://example.com:8443/"
+        );
+        assert_eq!(location("[::1]:80", 443, "/"), "https://[::1]/");
+        assert_eq!(location("[::1]", 443, "/"), "https://[::1]/");
+
+        assert_eq!(host("https://example.com"), "example.com");
+        assert_eq!(host("http://example.com:8080/blog"), "example.com:8080");
+        assert_eq!(host("example.com/"), "example.com");
+        assert_eq!(host("https://[::1]:8443"), "[::1]:8443");
+
+        let mut config = Config::default();
+        assert_eq!(
+            strict_transport_security(&config).as_deref(),
+            Some("max-age=31536000")
+        );
+        config.hsts_include_subdomains = true;
+        assert_eq!(
+            strict_transport_security(&config).as_deref(),
+            Some("max-age=31536000; includeSubDomains")
+        );
+        config.hsts_max_age = 0;
+        assert_eq!(strict_transport_security(&config), None);
+    }
+}
@@ -1,147 +1,81 @@
+mod article_index;
+mod cache;
+mod cli;
+mod config;
+mod error;
+mod export;
+mod feed;
+mod highlight;
+mod https;
 mod md_ex;
-
-use actix_web::{dev::Service, get, web, App, HttpResponse, HttpServer, Responder};
-use md_ex::ExtendedMd;
+mod pages;
+mod post;
+mod precompressed;
+mod preview;
+mod redirects;
+mod search;
+mod sitemap;
+mod toc;
+
+use actix_web::http::header::{self, HeaderName, HeaderValue};
+use actix_web::http::StatusCode;
+use actix_web::{
+    dev::Service, get, middleware, routes, web, App, HttpRequest, HttpResponse, HttpServer,
+};
+use article_index::{ArticleIndex, Articles};
+use clap::Parser;
+use cli::{Cli, Command};
+use config::Config;
+use error::AppError;
+use futures::future::Either;
+use md_ex::HeaderError;
+use post::Article;
 use serde::Deserialize;
 
 use html_template::{html, Root};
 
 use std::collections::BTreeMap;
 use std::fs::File;
-use std::io::{self, BufReader};
-use std::path::PathBuf;
-use tokio::fs::read_dir;
+use std::io::BufReader;
+use std::path::Path;
 
 use time::OffsetDateTime;
 
 use openssl::ssl::{SslAcceptor, SslFiletype, SslMethod};
 
-const BASE_URL: &str = "https://louissven.xyz";
-
-mod config {
-    use lazy_static::lazy_static;
-    use std::env::vars;
-
-    lazy_static! {
-        pub static ref FS_DATA_PATH: String = {
-            vars().find(|(k, _v)| k == "FS_DATA_PATH")
-                .map(|(_key, value)| value)
-                .unwrap_or_else(|| "./data".to_string())
-        };
-
-        pub static ref FS_MEDIA_PATH: String = {
-            vars().find(|(k, _v)| k == "FS_MEDIA_PATH")
-                .map(|(_key, value)| value)
-                .unwrap_or_else(|| "./media".to_string())
-        };
-
-        pub static ref FS_ARTICLES_PATH: String = {
-            vars().find(|(k, _v)| k == "FS_ARTICLES_PATH")
-                .map(|(_key, value)| value)
-                .unwrap_or_else(|| "./articles".to_string())
-        };
-
-        pub static ref IP_BIND: String = {
-            vars().find(|(k, _v)| k == "IP_BIND")
-                .map(|(_key, value)| value)
-                .unwrap_or_else(|| "0.0.0.0".to_string())
-        };
-
-        pub static ref HTTP_PORT: u16 = {
-            vars().find(|(k, _v)| k == "HTTP_PORT")
-                .map(|(_key, value)| value.parse().expect("invalid HTTP_PORT value"))
-                .unwrap_or(80)
-        };
-
-        pub static ref HTTPS_PORT: u16 = {
-            vars().find(|(k, _v)| k == "HTTPS_PORT")
-                .map(|(_key, value)| value.parse().expect("invalid HTTPS_PORT value"))
-                .unwrap_or(443)
-        };
-
-        pub static ref PRIVATE_KEY_FILEPATH: Option<String> = {
-            vars().find(|(k, _v)| k == "PRIVATE_KEY_FILEPATH")
-                .map(|(_key, value)| value)
-                .or_else(|| {
-                    log::warn!("No 'PRIVATE_KEY_FILEPATH' found in env, defaulting to http");
-                    None
-                })
-        };
-
-        pub static ref CERTIFICATE_CHAIN_FILEPATH: Option<String> = {
-            vars().find(|(k, _v)| k == "CERTIFICATE_CHAIN_FILEPATH")
-                .map(|(_key, value)| value)
-                .or_else(|| {
-                    log::warn!("No 'CERTIFICATE_CHAIN_FILEPATH' found in env, defaulting to http");
-                    None
-                })
-        };
-
-        pub static ref INDEX_MD_FILEPATH: String = {
-            vars().find(|(k, _v)| k == "INDEX_MD_FILEPATH")
-                .map(|(_key, value)| value)
-                .unwrap_or_else(|| "./data/index.md".to_string())
-        };
-
-        pub static ref RENDER_WIP: bool = {
-            vars().find(|(k, _v)| k == "RENDER_WIP")
-                .map(|(_key, value)| !value.is_empty())
-                .unwrap_or(false)
-        };
-
-        #[allow(clippy::assertions_on_constants)]
-        static ref _ASSERT: () = assert!(*HTTP_PORT != *HTTPS_PORT, "cannot use the same port for http and https");
-
-    }
-}
-
 #[derive(Deserialize)]
 struct Page {
     #[serde(default)]
     pub p: usize,
 }
 
-async fn page_404() -> HttpResponse {
-    let body: Root = html! {
-        <!DOCTYPE html>
-        <html>
-            <head>
-            { common_head("Page not found".to_string(), None, None)}
-            </head>
-            <body>
-                <header>
-                { common_header() }
-                </header>
-                <main>
-                <h1>"Page not found"</h1>
-                </main>
-                <footer>
-                { common_footer() }
-                </footer>
-            </body>
-        </html>
-    }
-    .into();
-    HttpResponse::NotFound()
-        .content_type(mime::TEXT_HTML)
-        .body(body.to_string())
+fn not_found_page() -> String {
+    error_page(StatusCode::NOT_FOUND, None)
 }
 
-fn page_500(e: impl std::error::Error) -> HttpResponse {
-    let error = e.to_string();
+/// `details` are only given in debug mode
+fn error_page(status: StatusCode, details: Option<String>) -> String {
+    let title = match status {
+        StatusCode::NOT_FOUND => "Page not found",
+        _ => status.canonical_reason().unwrap_or("Error"),
+    };
     let body: Root = html! {
         <!DOCTYPE html>
         <html>
             <head>
-            { common_head("Page not found".to_string(), None, None)}
+            { common_head(title.to_string(), None, None)}
             </head>
             <body>
                 <header>
                 { common_header() }
                 </header>
                 <main>
-                <h1>"Internal server error"</h1>
-                <pre><code>{ error.to_string() }</code></pre>
+                <h1>{ title.to_string() }</h1>
+                {[move]
+                    details.iter().map(|v| html! {
+                        <pre><code>{[move] v.clone()}</code></pre>
+                    }).collect()
+                }
                 </main>
                 <footer>
                 { common_footer() }
@@ -150,58 +84,62 @@ fn page_500(e: impl std::error::Error) -> HttpResponse {
         </html>
     }
     .into();
-    HttpResponse::NotFound()
-        .content_type(mime::TEXT_HTML)
-        .body(body.to_string())
-}
-
-macro_rules! yeet_404 {
-    ($v:expr) => {
-        match $v {
-            Ok(v) => v,
-            Err(_) => return page_404().await,
-        }
-    };
+    body.to_string()
 }
 
-macro_rules! yeet_500 {
-    ($v:expr) => {
-        match $v {
-            Ok(v) => v,
-            Err(e) => return page_500(e),
-        }
-    };
+async fn page_404() -> Result<HttpResponse, AppError> {
+    Err(AppError::NotFound)
 }
 
 fn common_header() -> String {
+    let config = config::get();
     html! {
         <div id="header_top_div">
-            <a href="/" class="header_element">home</a>
-            <a href="/articles" class="header_element">articles</a>
-            <a href="/data-policy" class="header_element">"data policy"</a>
-            <a href="https://github.com/lorlouis" class="header_element">github</a>
-            <a href="/rss" class="header_element">"rss"</a>
+        {[move]
+            config.nav.iter()
+                .map(|link| (link.name.as_str(), link.url.clone()))
+                .chain(pages::get().nav().map(|(label, page)| (label, format!("/{}", page.slug))))
+                .chain(config.social.iter().map(|link| (link.name.as_str(), link.url.clone())))
+                .map(|(label, url)| html! {
+                    <a href={[move] format!("\"{}\"", url)} class="header_element">
+                        {[move] label.to_string()}
+                    </a>
+                }).collect()
+        }
         </div>
     }
     .to_string()
 }
 
 fn copyright_str() -> String {
+    let config = config::get();
     let now = OffsetDateTime::now_utc();
     let year = now.year();
-    format!("copyright Louis Sven Goulet 2023-{}", year)
+    if year > config.start_year {
+        format!("copyright {} {}-{}", config.owner, config.start_year, year)
+    } else {
+        format!("copyright {} {}", config.owner, year)
+    }
 }
 
 fn copyright() -> String {
-    html! {
-        <p id="copyright">
-        "Found a typo?"
-        <a href="https://www.github.com/lorlouis/blog">" open a pr!"</a>
-        <br>
-        { copyright_str() }
-        </p>
+    match &config::get().source_url {
+        Some(url) => html! {
+            <p id="copyright">
+            "Found a typo?"
+            <a href={[move] format!("\"{}\"", url)}>" open a pr!"</a>
+            <br>
+            { copyright_str() }
+            </p>
+        }
+        .to_string(),
+        None => html! {
+            <p id="copyright">
+            { copyright_str() }
+            </p>
+        }
+        .to_string(),
     }
-    .to_string()
 }
 
 fn common_footer() -> String {
@@ -213,7 +151,7 @@ fn common_footer() -> String {
 }
 
 fn common_head(title: String, author: Option<String>, blurb: Option<String>) -> String {
-    let author = author.unwrap_or_else(|| "Louis Sven Goulet".to_string());
+    let author = author.unwrap_or_else(|| config::get().default_author.clone());
     html! {
         <base href="/" >
         <link rel="stylesheet" href="data/site.css">
@@ -231,17 +169,23 @@ fn common_head(title: String, author: Option<String>, blurb: Option<String>) ->
             }).collect()
         }
         <meta name="author" content={[move] format!("\"{}\"", author)}>
-        <link rel="stylesheet" href="/data/highlight/styles/nord.min.css">
-        <script src="/data/highlight/highlight.min.js"></script>
-        <script>hljs.highlightAll();</script>
+        {
+            config::get().social.iter().map(|link| html!{
+                <link rel="me" href={[move] format!("\"{}\"", link.url)}>
+            }).collect()
+        }
+        <link rel="alternate" type="application/rss+xml" title="RSS" href="/rss.xml">
+        <link rel="alternate" type="application/atom+xml" title="Atom" href="/atom.xml">
+        <link rel="alternate" type="application/feed+json" title="JSON Feed" href="/feed.json">
+        <link rel="stylesheet" href="/highlight.css">
     }
     .to_string()
 }
 
-async fn basic_md_page(path: &str) -> impl Responder {
-    let md_file = yeet_500!(File::open(path));
+fn md_page(path: &Path) -> Result<String, HeaderError> {
+    let md_file = File::open(path)?;
 
-    let markdown = yeet_500!(md_ex::ExtendedMd::from_bufread(BufReader::new(md_file)));
+    let markdown = md_ex::ExtendedMd::from_bufread(BufReader::new(md_file))?;
     let title = markdown.header.get("Title").cloned().unwrap_or_default();
     let body: Root = html! {
         <!DOCTYPE html>
@@ -263,26 +207,40 @@ async fn basic_md_page(path: &str) -> impl Responder {
         </html>
     }
     .into();
-    HttpResponse::Ok()
-        .content_type(mime::TEXT_HTML)
-        .body(body.to_string())
+    Ok(body.to_string())
 }
 
-#[get("/")]
-async fn index() -> impl Responder {
-    let index_file = yeet_500!(File::open(config::INDEX_MD_FILEPATH.as_str()));
+async fn basic_md_page(path: &Path) -> Result<HttpResponse, AppError> {
+    let body = md_page(path)?;
+
+    Ok(HttpResponse::Ok().content_type(mime::TEXT_HTML).body(body))
+}
 
-    let markdown = yeet_500!(md_ex::ExtendedMd::from_bufread(BufReader::new(index_file)));
+/// styles the spans of the highlighted code blocks
+#[get("/highlight.css")]
+async fn highlight_css() -> HttpResponse {
+    HttpResponse::Ok()
+        .content_type(mime::TEXT_CSS_UTF_8)
+        .body(highlight::theme_css())
+}
+
+/// one of the markdown files of the pages directory
+#[get("/{page}")]
+async fn static_page(slug: web::Path<String>) -> Result<HttpResponse, AppError> {
+    let page = pages::get().find(&slug).ok_or(AppError::NotFound)?;
+    basic_md_page(&page.path).await
+}
 
-    let posts = yeet_500!(get_articles().await);
+fn index_page(posts_ref: &[Article]) -> Result<String, HeaderError> {
+    let index_file = File::open(config::get().index_md_filepath.as_str())?;
 
-    let posts_ref = posts.as_slice();
+    let markdown = md_ex::ExtendedMd::from_bufread(BufReader::new(index_file))?;
 
     let body: Root = html! {
         <!DOCTYPE html>
         <html>
             <head>
-            { common_head("Louis' imperfect blog".to_string(), None, None)}
+            { common_head(config::get().site_name.clone(), None, None)}
             </head>
             <body>
                 <header>
@@ -300,40 +258,58 @@ async fn index() -> impl Responder {
         </html>
     }
     .into();
-    HttpResponse::Ok()
-        .content_type(mime::TEXT_HTML)
-        .body(body.to_string())
+    Ok(body.to_string())
+}
+
+#[get("/")]
+async fn index(
+    req: HttpRequest,
+    article_index: web::Data<ArticleIndex>,
+) -> Result<HttpResponse, AppError> {
+    let articles_ = article_index.articles().await?;
+
+    // unlike the articles, index.md isn't watched
+    let index_path = Path::new(config::get().index_md_filepath.as_str());
+    let index_md = std::fs::read(index_path)?;
+    let modified = std::fs::metadata(index_path)?.modified()?;
+    let validators = cache::Validators::new(
+        &[articles_.digest().as_bytes(), &index_md],
+        articles_.indexed_at.max(modified.into()),
+    );
+    validators.respond(&req, mime::TEXT_HTML, || Ok(index_page(&articles_.posts)?))
+}
+
+fn build_articles_html_list(posts: &[Article], count: usize, skip: usize) -> String {
+    articles_html_list(posts.iter().skip(skip).take(count).map(|post| (post, None)))
 }
 
-fn build_articles_html_list(
-    posts: &[(String, String, BTreeMap<String, String>)],
-    count: usize,
-    skip: usize,
-) -> String {
-    let trimmed_articles: Vec<_> = posts.iter().skip(skip).take(count).collect();
+/// the snippet, already html, follows the blurb
+fn articles_html_list<'a>(entries: impl Iterator<Item = (&'a Article, Option<String>)>) -> String {
+    let entries: Vec<_> = entries.collect();
     html! {
         <div id="article_container" >
         { [move]
-            trimmed_articles.iter()
-                .map(|(date, name, data)| {
-                    let title = data.get("Title")
-                        .unwrap_or(name);
-                    let blurb = data.get("Blurb");
+            entries.iter()
+                .map(|(post, snippet)| {
                     html! {
                     <article>
                         <h3 class="list_element">
-                            {[move] format!("{}", date) }
+                            {[move] post.date.date().to_string() }
                         </h3>
                         <h3 class="list_element">
-                            <a href={[move] format!("\"article/{}\"", name)}>
-                                {[move] format!("{}", title)}
+                            <a href={[move] format!("\"article/{}\"", post.slug)}>
+                                {[move] post.title.clone()}
                             </a>
                         </h3>
-                        {[move] blurb.iter().map(|v| html!{
+                        {[move] post.blurb.iter().map(|v| html!{
                             <blockquote class="blurb">
                             {v.to_string()}
                             </blockquote>
                         }).collect()}
+                        {[move] snippet.iter().map(|v| html!{
+                            <p class="snippet">{[move] v.clone()}</p>
+                        }).collect()}
+                        <p class="reading_time">{[move] reading_time(post)}</p>
                     </article>
                 }
             }).collect()
@@ -343,148 +319,173 @@ fn build_articles_html_list(
     .to_string()
 }
 
+#[routes]
+#[get("/rss.xml")]
 #[get("/rss")]
-async fn rss<'a>() -> impl Responder + 'a {
-    let articles_ = yeet_500!(get_articles().await);
+async fn rss(
+    req: HttpRequest,
+    article_index: web::Data<ArticleIndex>,
+) -> Result<HttpResponse, AppError> {
+    site_feed(req, article_index, feed::Format::Rss).await
+}
 
-    HttpResponse::Ok()
-        .content_type(mime::TEXT_XML)
-        .body(build_rss(&articles_))
+#[get("/atom.xml")]
+async fn atom(
+    req: HttpRequest,
+    article_index: web::Data<ArticleIndex>,
+) -> Result<HttpResponse, AppError> {
+    site_feed(req, article_index, feed::Format::Atom).await
 }
 
-fn build_rss(posts: &[(String, String, BTreeMap<String, String>)]) -> String {
-    let trimmed_articles = posts;
+#[get("/feed.json")]
+async fn json_feed(
+    req: HttpRequest,
+    article_index: web::Data<ArticleIndex>,
+) -> Result<HttpResponse, AppError> {
+    site_feed(req, article_index, feed::Format::Json).await
+}
 
-    // just a little lie
-    html! {
-        <?xml version="1.0" encoding="UTF-8" ?>
-        <rss version="2.0">
-        <channel>
-        <title>"<![CDATA[Louis' imperfect blog]]>"</title>
-        <description>"<![CDATA[Louis' imperfect blog's RSS feed]]>"</description>
-        <link>{BASE_URL.into()}</link>
-        <copyright>{ copyright_str() }</copyright>
-        {
-            posts.first().map(|post| {
-                let date = &post.0;
-                html!{<pubDate>{date.to_string()}</pubDate>}.to_string()
-            }).unwrap_or_default()
-        }
-        <ttl>1800</ttl>
+async fn site_feed(
+    req: HttpRequest,
+    article_index: web::Data<ArticleIndex>,
+    format: feed::Format,
+) -> Result<HttpResponse, AppError> {
+    let articles_ = article_index.articles().await?;
+
+    build_feed(
+        &req,
+        format,
+        &feed_channel(feed::Filter::All, ""),
+        &articles_,
+    )
+}
 
-        { [move]
-            trimmed_articles.iter()
-                .map(|(date, name, data)| -> io::Result<html_template::Node> {
-                    let title = data.get("Title")
-                        .unwrap_or(name);
-                    let full_title = format!(
-                        "{} – {}",
-                        title,
-                        data.get("Blurb")
-                        .map(|v| v.as_str())
-                        .unwrap_or("")
-                    );
-
-                    let mut md_path = PathBuf::from(config::FS_ARTICLES_PATH.as_str());
-                    md_path.push(&name);
-
-                    let file = File::open(md_path)?;
-
-                    let markdown = md_ex::ExtendedMd::from_bufread(BufReader::new(file))
-                        .map_err(|e| io::Error::other(e))?;
-
-                    Ok(html! {
-                    <item>
-                        <title>{[move] format!("<![CDATA[{}]]>", full_title) }</title>
-                        <link>{[move] format!("{}/article/{}", BASE_URL, name)}</link>
-                        <description>{[move] format!("<![CDATA[{}]]>", article_page(&markdown, name)) }</description>
-                    </item>
-                    }
-                )
-            })
-            .filter_map(Result::ok)
-            .collect()
-        }
-        </channel>
-        </rss>
-    }.to_string()
-}
-
-async fn get_articles() -> io::Result<Vec<(String, String, BTreeMap<String, String>)>> {
-    let mut dir = read_dir(config::FS_ARTICLES_PATH.as_str()).await?;
-    let mut posts = Vec::new();
-    loop {
-        // ugly but I can't flatten due to the await
-        let res = dir.next_entry().await;
-        let entry = match res? {
-            Some(v) => v,
-            None => break,
-        };
+#[get("/sitemap.xml")]
+async fn sitemap_xml(
+    req: HttpRequest,
+    article_index: web::Data<ArticleIndex>,
+) -> Result<HttpResponse, AppError> {
+    let articles_ = article_index.articles().await?;
+
+    cache::Validators::listing(&articles_).respond(&req, sitemap::CONTENT_TYPE, || {
+        Ok(sitemap::build(
+            &config::get().base_url,
+            &articles_,
+            pages::get(),
+        )?)
+    })
+}
 
-        let metadata = entry.metadata().await?;
-        let entry_name = entry.file_name().to_string_lossy().to_lowercase();
-
-        let is_markdown =
-            entry_name.ends_with(".md") || (*config::RENDER_WIP && entry_name.ends_with(".md.wip"));
-
-        if metadata.is_file() && is_markdown {
-            // normal std::fs::File because tokio's async BufReader is really annoying
-            let file = BufReader::new(File::open(entry.path())?);
-            let article_data = match md_ex::ExtendedMd::read_header(file) {
-                Ok(v) => v,
-                Err(e) => {
-                    // ignore the error
-                    log::error!(
-                        "Ran into error:{e:?}; for file: '{}'",
-                        entry.path().display()
-                    );
-                    continue;
-                }
-            };
-            let date = article_data
-                .get("Date")
-                .cloned()
-                .unwrap_or_else(|| "31005-12-01".to_string());
-            posts.push((
-                date,
-                entry.file_name().to_string_lossy().to_string(),
-                article_data,
-            ));
-        }
+#[get("/tags/{tag}/{feed}")]
+async fn tag_feed(
+    req: HttpRequest,
+    article_index: web::Data<ArticleIndex>,
+    path: web::Path<(String, String)>,
+) -> Result<HttpResponse, AppError> {
+    let (name, file_name) = path.into_inner();
+    let format = feed::Format::from_file_name(&file_name).ok_or(AppError::NotFound)?;
+    let slug = md_ex::slugify(&name);
+
+    let articles_ = article_index.articles().await?;
+
+    let name = tag_name(&articles_.posts, &slug).ok_or(AppError::NotFound)?;
+
+    let channel = feed_channel(feed::Filter::Tag(slug.clone()), &format!("#{}", name));
+    build_feed(&req, format, &channel, &articles_)
+}
+
+#[get("/authors/{author}/{feed}")]
+async fn author_feed(
+    req: HttpRequest,
+    article_index: web::Data<ArticleIndex>,
+    path: web::Path<(String, String)>,
+) -> Result<HttpResponse, AppError> {
+    let (name, file_name) = path.into_inner();
+    let format = feed::Format::from_file_name(&file_name).ok_or(AppError::NotFound)?;
+    let slug = md_ex::slugify(&name);
+
+    let articles_ = article_index.articles().await?;
+
+    let name = author_name(&articles_.posts, &slug).ok_or(AppError::NotFound)?;
+
+    let channel = feed_channel(feed::Filter::Author(slug.clone()), name);
+    build_feed(&req, format, &channel, &articles_)
+}
+
+/// `label` describes the filter, it is appended to the feed's title
+fn feed_channel(filter: feed::Filter, label: &str) -> feed::Channel<'static> {
+    let config = config::get();
+    let site_name = &config.site_name;
+    let (title, description, link, path) = match &filter {
+        feed::Filter::All => (
+            site_name.clone(),
+            format!("{}'s RSS feed", site_name),
+            format!("{}/", config.base_url),
+            String::new(),
+        ),
+        feed::Filter::Tag(slug) => (
+            format!("{} – {}", site_name, label),
+            format!("Articles tagged {} on {}", label, site_name),
+            format!("{}/tags/{}", config.base_url, slug),
+            format!("/tags/{}", slug),
+        ),
+        feed::Filter::Author(slug) => (
+            format!("{} – {}", site_name, label),
+            format!("Articles written by {} on {}", label, site_name),
+            format!("{}/", config.base_url),
+            format!("/authors/{}", slug),
+        ),
+    };
+
+    feed::Channel {
+        title,
+        description,
+        base_url: &config.base_url,
+        link,
+        path,
+        copyright: copyright_str(),
+        author: &config.default_author,
+        words_per_minute: config.words_per_minute,
+        filter,
     }
-    posts.sort_unstable_by(|s, o| {
-        s.0.cmp(&o.0)
-            // make the oldest articles appear at the end
-            .reverse()
-    });
-    Ok(posts)
 }
 
-#[get("/articles")]
-async fn articles<'a>(info: web::Query<Page>) -> impl Responder + 'a {
-    const ARTICLES_PER_PAGE: usize = 8;
+fn build_feed(
+    req: &HttpRequest,
+    format: feed::Format,
+    channel: &feed::Channel,
+    articles_: &Articles,
+) -> Result<HttpResponse, AppError> {
+    cache::Validators::listing(articles_).respond(req, format.content_type(), || {
+        Ok(feed::build(format, channel, articles_)?)
+    })
+}
 
-    let page = info.0.p;
+const ARTICLES_PER_PAGE: usize = 8;
 
-    let articles = yeet_500!(get_articles().await);
+fn last_page(posts: &[Article]) -> usize {
+    posts.len() / ARTICLES_PER_PAGE
+}
 
-    let last_page = articles.len() / ARTICLES_PER_PAGE;
+/// paginated list of articles, `link` is the path the page links point to
+fn articles_list_page(title: &str, posts: &[Article], page: usize, link: &str) -> String {
+    let last_page = last_page(posts);
     let cur_page = last_page.min(page);
 
-    let articles_ref = articles.as_slice();
-
+    // pages are linked by path rather than by query so the static export
+    // can follow them
     let body: Root = html! {
         <!DOCTYPE html>
         <html>
             <head>
-            { common_head("articles".to_string(), None, None) }
+            { common_head(title.to_string(), None, None) }
             </head>
             <body>
                 { common_header() }
                 <main>
-                <h1>Articles</h1>
+                <h1>{title.to_string()}</h1>
                 {[move] build_articles_html_list(
-                            articles_ref,
+                            posts,
                             ARTICLES_PER_PAGE,
                             ARTICLES_PER_PAGE * cur_page)
                 }
@@ -492,25 +493,25 @@ async fn articles<'a>(info: web::Query<Page>) -> impl Responder + 'a {
                 <footer>
                 <div id="page_link_div">
                 <a
-                    href="/articles"
+                    href={format!{"\"{}\" ", link}}
                     class="article_link"
                     id="link_first_page"
                     title="fist page"
                 >&lt;&lt;</a>
                 <a
-                    href={format!{"\"articles?p={}\" ", cur_page.saturating_sub(1)}}
+                    href={format!{"\"{}/page/{}\" ", link, cur_page.saturating_sub(1)}}
                     class="article_link"
                     id="link_previous_page"
                     title="previous page"
                 >&lt;</a>
                 <a
-                    href={format!{"\"articles?p={}\" ", cur_page.saturating_add(1)}}
+                    href={format!{"\"{}/page/{}\" ", link, cur_page.saturating_add(1).min(last_page)}}
                     class="article_link"
                     id="link_next_page"
                     title="next page"
                 >&gt;</a>
                 <a
-                    href={format!{"\"articles?p={}\" ", last_page}}
+                    href={format!{"\"{}/page/{}\" ", link, last_page}}
                     class="article_link"
                     id="link_last_page"
                     title="last page"
@@ -523,28 +524,95 @@ async fn articles<'a>(info: web::Query<Page>) -> impl Responder + 'a {
     }
     .into();
 
-    HttpResponse::Ok()
-        .content_type(mime::TEXT_HTML)
-        .body(body.to_string())
+    body.to_string()
+}
+
+#[get("/articles")]
+async fn articles(
+    req: HttpRequest,
+    article_index: web::Data<ArticleIndex>,
+    info: web::Query<Page>,
+) -> Result<HttpResponse, AppError> {
+    articles_response(req, article_index, info.0.p).await
+}
+
+#[get("/articles/page/{p}")]
+async fn articles_paged(
+    req: HttpRequest,
+    article_index: web::Data<ArticleIndex>,
+    page: web::Path<usize>,
+) -> Result<HttpResponse, AppError> {
+    articles_response(req, article_index, page.into_inner()).await
+}
+
+async fn articles_response(
+    req: HttpRequest,
+    article_index: web::Data<ArticleIndex>,
+    page: usize,
+) -> Result<HttpResponse, AppError> {
+    let articles_ = article_index.articles().await?;
+    let posts = articles_.posts.as_slice();
+
+    cache::Validators::listing(&articles_).respond(&req, mime::TEXT_HTML, || {
+        Ok(articles_list_page("Articles", posts, page, "articles"))
+    })
+}
+
+/// slug -> (name as first written, number of articles)
+fn tag_counts(posts: &[Article]) -> BTreeMap<String, (String, usize)> {
+    let mut tags: BTreeMap<String, (String, usize)> = BTreeMap::new();
+    for post in posts.iter() {
+        for name in post.tags.iter() {
+            tags.entry(md_ex::slugify(name))
+                .or_insert_with(|| (name.to_string(), 0))
+                .1 += 1;
+        }
+    }
+    tags
+}
+
+/// the tag as written in the most recent article using it
+fn tag_name<'a>(posts: &'a [Article], slug: &str) -> Option<&'a str> {
+    posts
+        .iter()
+        .find_map(|post| post.tags.iter().find(|t| md_ex::slugify(t) == slug))
+        .map(String::as_str)
 }
 
-fn article_page(markdown: &ExtendedMd, title: &String) -> String {
-    let real_title = markdown.header.get("Title").unwrap_or(title);
-    let author = markdown.header.get("Author");
-    let blurb = markdown.header.get("Blurb");
+fn author_name<'a>(posts: &'a [Article], slug: &str) -> Option<&'a str> {
+    posts
+        .iter()
+        .find(|post| post.has_author(slug))
+        .and_then(|post| post.author.as_deref())
+}
+
+fn tags_page(posts: &[Article]) -> String {
+    let tags = tag_counts(posts);
 
     let body: Root = html! {
         <!DOCTYPE html>
         <html>
             <head>
-                {common_head(real_title.clone(), author.cloned(), blurb.cloned())}
+            { common_head("tags".to_string(), None, None) }
             </head>
             <body>
                 <header>
                 { common_header() }
                 </header>
                 <main>
-                { markdown.to_html() }
+                <h1>Tags</h1>
+                <ul class="tag_list">
+                {[move]
+                    tags.iter().map(|(slug, (name, count))| html! {
+                        <li>
+                            <a href={[move] format!("\"tags/{}\"", slug)} class="tag">
+                                {[move] name.clone()}
+                            </a>
+                            {[move] format!(" ({})", count)}
+                        </li>
+                    }).collect()
+                }
+                </ul>
                 </main>
                 <footer>
                 { common_footer() }
@@ -557,19 +625,237 @@ fn article_page(markdown: &ExtendedMd, title: &String) -> String {
     body.to_string()
 }
 
-#[get("/article/{title}")]
-async fn article<'a>(title: web::Path<String>) -> impl Responder + 'a {
-    let title = title.into_inner();
-    let mut md_path = PathBuf::from(config::FS_ARTICLES_PATH.as_str());
-    md_path.push(&title);
+#[get("/tags")]
+async fn tag_list(
+    req: HttpRequest,
+    article_index: web::Data<ArticleIndex>,
+) -> Result<HttpResponse, AppError> {
+    let articles_ = article_index.articles().await?;
 
-    let file = yeet_404!(File::open(md_path));
+    cache::Validators::listing(&articles_)
+        .respond(&req, mime::TEXT_HTML, || Ok(tags_page(&articles_.posts)))
+}
 
-    let markdown = yeet_404!(md_ex::ExtendedMd::from_bufread(BufReader::new(file)));
+/// `None` when no article uses the tag
+fn tagged_articles_page(posts: &[Article], slug: &str, page: usize) -> Option<String> {
+    let name = tag_name(posts, slug)?;
+    let tagged: Vec<Article> = posts
+        .iter()
+        .filter(|post| post.has_tag(slug))
+        .cloned()
+        .collect();
+
+    Some(articles_list_page(
+        &format!("Articles tagged \"{}\"", name),
+        &tagged,
+        page,
+        &format!("tags/{}", slug),
+    ))
+}
 
-    HttpResponse::Ok()
+#[get("/tags/{tag}")]
+async fn tagged_articles(
+    req: HttpRequest,
+    article_index: web::Data<ArticleIndex>,
+    name: web::Path<String>,
+    info: web::Query<Page>,
+) -> Result<HttpResponse, AppError> {
+    tagged_articles_response(req, article_index, &name.into_inner(), info.0.p).await
+}
+
+#[get("/tags/{tag}/page/{p}")]
+async fn tagged_articles_paged(
+    req: HttpRequest,
+    article_index: web::Data<ArticleIndex>,
+    path: web::Path<(String, usize)>,
+) -> Result<HttpResponse, AppError> {
+    let (name, page) = path.into_inner();
+    tagged_articles_response(req, article_index, &name, page).await
+}
+
+async fn tagged_articles_response(
+    req: HttpRequest,
+    article_index: web::Data<ArticleIndex>,
+    name: &str,
+    page: usize,
+) -> Result<HttpResponse, AppError> {
+    let slug = md_ex::slugify(name);
+
+    let articles_ = article_index.articles().await?;
+    // preconditions only apply to a page that exists
+    tag_name(&articles_.posts, &slug).ok_or(AppError::NotFound)?;
+
+    cache::Validators::listing(&articles_).respond(&req, mime::TEXT_HTML, || {
+        tagged_articles_page(&articles_.posts, &slug, page).ok_or(AppError::NotFound)
+    })
+}
+
+fn tag_links(post: &Article) -> String {
+    if post.tags.is_empty() {
+        return String::new();
+    }
+    html! {
+        <p class="tag_list">
+        "Tags: "
+        {[move]
+            post.tags.iter().map(|tag| html! {
+                <a href={[move] format!("\"tags/{}\"", md_ex::slugify(tag))} class="tag">
+                    {[move] tag.clone()}
+                </a>
+            }).collect()
+        }
+        </p>
+    }
+    .to_string()
+}
+
+#[derive(Deserialize)]
+struct SearchQuery {
+    #[serde(default)]
+    pub q: String,
+}
+
+fn search_results_page(query: &str, results: Vec<(&Article, String)>) -> String {
+    let summary = match (query.trim().is_empty(), results.len()) {
+        (true, _) => String::new(),
+        (false, 0) => "No article matches your search".to_string(),
+        (false, 1) => "1 article found".to_string(),
+        (false, n) => format!("{} articles found", n),
+    };
+    let body: Root = html! {
+        <!DOCTYPE html>
+        <html>
+            <head>
+            { common_head("Search".to_string(), None, None) }
+            </head>
+            <body>
+                <header>
+                { common_header() }
+                </header>
+                <main>
+                <h1>Search</h1>
+                <form action="/search" method="get" class="search">
+                    <input type="search" name="q" value={[move] format!("\"{}\"", md_ex::escape_html(query))}>
+                    <button type="submit">Search</button>
+                </form>
+                <p>{ summary }</p>
+                {[move] articles_html_list(
+                    results.into_iter().map(|(post, snippet)| (post, Some(snippet)))
+                )}
+                </main>
+                <footer>
+                { common_footer() }
+                </footer>
+            </body>
+        </html>
+    }
+    .into();
+
+    body.to_string()
+}
+
+#[get("/search")]
+async fn search_page(
+    article_index: web::Data<ArticleIndex>,
+    info: web::Query<SearchQuery>,
+) -> Result<HttpResponse, AppError> {
+    let articles_ = article_index.articles().await?;
+
+    Ok(HttpResponse::Ok()
+        .content_type(mime::TEXT_HTML)
+        .body(search_results_page(&info.q, articles_.search(&info.q))))
+}
+
+/// `1234 words, 7 min read`
+fn reading_time(post: &Article) -> String {
+    format!(
+        "{} words, {} min read",
+        post.word_count,
+        post.reading_minutes(config::get().words_per_minute)
+    )
+}
+
+/// the reading time, followed by the day of the last revision if any
+fn article_meta(post: &Article) -> String {
+    if post.was_updated() {
+        format!("{}, updated on {}", reading_time(post), post.updated.date())
+    } else {
+        reading_time(post)
+    }
+}
+
+fn article_page(post: &Article, body: &str) -> String {
+    let body: Root = html! {
+        <!DOCTYPE html>
+        <html>
+            <head>
+                {common_head(post.title.clone(), post.author.clone(), post.blurb.clone())}
+            </head>
+            <body>
+                <header>
+                { common_header() }
+                </header>
+                <main>
+                <p class="reading_time">{ article_meta(post) }</p>
+                { body.to_string() }
+                { tag_links(post) }
+                </main>
+                <footer>
+                { common_footer() }
+                </footer>
+            </body>
+        </html>
+    }
+    .into();
+
+    body.to_string()
+}
+
+#[get("/article/{slug}")]
+async fn article(
+    req: HttpRequest,
+    slug: web::Path<String>,
+    article_index: web::Data<ArticleIndex>,
+) -> Result<HttpResponse, AppError> {
+    let articles_ = article_index.articles().await?;
+    // only indexed articles are served, the slug is never used as a path
+    let post = match articles_.get(&slug) {
+        Some(v) => v,
+        None => {
+            // links from before articles had slugs
+            let post = articles_
+                .get_by_file_name(&slug)
+                .ok_or(AppError::NotFound)?;
+            return Ok(HttpResponse::MovedPermanently()
+                .insert_header((header::LOCATION, format!("/article/{}", post.slug)))
+                .finish());
+        }
+    };
+
+    cache::Validators::article(&articles_, post).respond(&req, mime::TEXT_HTML, || {
+        Ok(article_page(post, articles_.body(post)))
+    })
+}
+
+/// drafts and scheduled articles are only reachable through the link
+/// `website preview` prints
+#[get("/preview/{token}")]
+async fn draft_preview(
+    token: web::Path<String>,
+    article_index: web::Data<ArticleIndex>,
+) -> Result<HttpResponse, AppError> {
+    let secret = config::get()
+        .preview_secret
+        .as_deref()
+        .ok_or(AppError::NotFound)?;
+    let articles_ = article_index.articles().await?;
+    let post = preview::find(&articles_, secret, &token).ok_or(AppError::NotFound)?;
+
+    Ok(HttpResponse::Ok()
         .content_type(mime::TEXT_HTML)
-        .body(article_page(&markdown, &title))
+        // the link may be shared, keep the draft out of search engines
+        .insert_header(("X-Robots-Tag", "noindex"))
+        .body(article_page(post, articles_.body(post))))
 }
 
 #[actix_web::main]
@@ -577,9 +863,129 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // configure logging
     env_logger::init_from_env(env_logger::Env::default().default_filter_or("info"));
 
-    let new_website = || {
+    let cli = Cli::parse();
+
+    let config = Config::load(cli.config.as_deref()).and_then(|mut config| {
+        match &cli.command {
+            Some(Command::Serve(args)) => args.apply(&mut config),
+            Some(Command::Build { .. }) => export::adjust_config(&mut config),
+            _ => (),
+        }
+        config.validate()
+    });
+    match config {
+        Ok(v) => config::init(v),
+        Err(e) => {
+            log::error!("{e}");
+            std::process::exit(1);
+        }
+    }
+    pages::init(pages::Pages::load(Path::new(
+        config::get().fs_pages_path.as_str(),
+    ))?);
+    redirects::init(redirects::Redirects::load(Path::new(
+        config::get().redirects_filepath.as_str(),
+    ))?);
+
+    match cli.command {
+        Some(Command::Serve(_)) => serve().await,
+        Some(Command::Build { out }) => export::build(&out),
+        Some(Command::New { slug }) => {
+            let path = cli::new_article(&slug)?;
+            println!("Created '{}'", path.display());
+            Ok(())
+        }
+        Some(Command::Check) => cli::check(),
+        Some(Command::Preview { slug }) => {
+            println!("{}", cli::preview_link(&slug)?);
+            Ok(())
+        }
+        None => serve().await,
+    }
+}
+
+/// the files of `dir` at `mount`, nothing when `dir` isn't a directory: `Files`
+/// would serve the working directory instead
+fn static_files(mount: &'static str, dir: &str) -> impl FnOnce(&mut web::ServiceConfig) {
+    let dir = dir.to_string();
+    move |cfg| {
+        if Path::new(&dir).is_dir() {
+            cfg.service(actix_files::Files::new(mount, dir).prefer_utf8(true));
+        } else {
+            log::warn!(
+                "No directory at '{}', nothing is served at '{}'",
+                dir,
+                mount
+            );
+        }
+    }
+}
+
+async fn serve() -> Result<(), Box<dyn std::error::Error>> {
+    let config = config::get();
+
+    let article_index = web::Data::new(ArticleIndex::new(
+        config.fs_articles_path.as_str(),
+        config.render_wip,
+    ));
+    // requests arriving while the first index is built would see an empty one
+    if let Err(e) = article_index.articles().await {
+        log::error!("Unable to index '{}': {e}", config.fs_articles_path);
+    }
+
+    let tls = config.private_key_filepath.is_some() && config.certificate_chain_filepath.is_some();
+    // browsers would keep asking for https if it was sent over plain http
+    let hsts = https::strict_transport_security(config).filter(|_| tls);
+
+    let new_website = move || {
         App::new()
+            .app_data(article_index.clone())
+            .app_data(
+                web::QueryConfig::default()
+                    .error_handler(|e, _| AppError::BadRequest(e.to_string()).into()),
+            )
+            .app_data(web::PathConfig::default().error_handler(|_, _| AppError::NotFound.into()))
             .wrap_fn(|req, srv| {
+                // compressed ahead of time, the file services would send the
+                // whole file for `Compress` to encode on every request
+                let res = match precompressed::respond(req.request()) {
+                    Some(v) => Either::Left(req.into_response(v)),
+                    None => Either::Right(srv.call(req)),
+                };
+                async move {
+                    Ok(match res {
+                        Either::Left(res) => res,
+                        Either::Right(res) => res.await?.map_into_boxed_body(),
+                    })
+                }
+            })
+            .wrap_fn(|req, srv| {
+                // the redirects only apply to paths nothing else serves, they
+                // are written decoded: `/café` rather than `/caf%C3%A9`
+                let path = req.match_info().unprocessed().to_string();
+                let article_index = req.app_data::<web::Data<ArticleIndex>>().cloned();
+                let res = srv.call(req);
+                async move {
+                    let res = res.await?;
+                    if res.status() != StatusCode::NOT_FOUND {
+                        return Ok(res);
+                    }
+                    let articles_ = match article_index {
+                        Some(v) => v.articles().await.unwrap_or_default(),
+                        None => Default::default(),
+                    };
+                    Ok(match redirects::resolve(&path, &articles_) {
+                        Some(to) => res.into_response(
+                            HttpResponse::MovedPermanently()
+                                .insert_header((header::LOCATION, to))
+                                .finish(),
+                        ),
+                        None => res,
+                    })
+                }
+            })
+            .wrap_fn(|req, srv| {
+                let id = error::request_id();
                 let connection_info = req.connection_info().clone();
                 let target: String = req.uri().to_string().escape_debug().collect();
                 let remote_addr = connection_info
@@ -594,33 +1000,75 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                     .unwrap_or("UNKNOWN".to_string());
 
                 log::info!(
-                    "Connection from: '{}'; With agent: '{}'; For target: '{}'",
+                    "[{}] Connection from: '{}'; With agent: '{}'; For target: '{}'",
+                    id,
                     remote_addr,
                     agent,
                     target
                 );
-                srv.call(req)
+                let res = srv.call(req);
+                async move {
+                    let mut res = res.await?;
+                    if let Some(e) = res.response().error() {
+                        if res.status().is_server_error() {
+                            log::error!("[{}] {:?}", id, e);
+                        } else {
+                            log::info!("[{}] {}", id, e);
+                        }
+                    }
+                    if let Ok(v) = HeaderValue::from_str(&id) {
+                        res.headers_mut()
+                            .insert(HeaderName::from_static("x-request-id"), v);
+                    }
+                    Ok(res)
+                }
+            })
+            .wrap(match &hsts {
+                Some(v) => middleware::DefaultHeaders::new()
+                    .add((header::STRICT_TRANSPORT_SECURITY, v.as_str())),
+                None => middleware::DefaultHeaders::new(),
             })
+            // outermost so every response, errors included, is compressed
+            .wrap(middleware::Compress::default())
             .service(index)
             .service(article)
+            .service(draft_preview)
             .service(articles)
+            .service(articles_paged)
+            .service(tag_list)
+            .service(tagged_articles)
+            .service(tagged_articles_paged)
             .service(rss)
-            .route(
-                "/data-policy",
-                web::get().to(|| basic_md_page("./data/data_policy.md")),
-            )
-            .service(
-                actix_files::Files::new("/media", config::FS_MEDIA_PATH.as_str()).prefer_utf8(true),
-            )
-            .service(
-                actix_files::Files::new("/data", config::FS_DATA_PATH.as_str()).prefer_utf8(true),
-            )
+            .service(atom)
+            .service(json_feed)
+            .service(sitemap_xml)
+            .service(highlight_css)
+            .service(search_page)
+            .service(tag_feed)
+            .service(author_feed)
+            .configure(static_files("/media", &config.fs_media_path))
+            .configure(static_files("/data", &config.fs_data_path))
+            // matches any single segment, the routes above take precedence
+            .service(static_page)
             .default_service(web::to(page_404))
     };
 
+    if !tls {
+        log::warn!("No private key or certificate chain configured, defaulting to http");
+    }
+
+    let redirect_to_https = move || {
+        App::new()
+            .configure(static_files(
+                https::ACME_CHALLENGE_PATH,
+                &config.fs_acme_challenge_path,
+            ))
+            .default_service(web::to(https::redirect))
+    };
+
     if let (Some(private_key), Some(cert)) = (
-        config::PRIVATE_KEY_FILEPATH.as_deref(),
-        config::CERTIFICATE_CHAIN_FILEPATH.as_deref(),
+        config.private_key_filepath.as_deref(),
+        config.certificate_chain_filepath.as_deref(),
     ) {
         // load TLS keys
         // to create a self-signed temporary cert for testing:
@@ -633,36 +1081,33 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
         futures::try_join!(
             // https
-            HttpServer::new(new_website)
+            HttpServer::new(new_website.clone())
                 .bind_openssl(
-                    format!("{}:{}", config::IP_BIND.as_str(), *config::HTTPS_PORT),
+                    format!("{}:{}", config.ip_bind.as_str(), config.https_port),
                     builder
                 )
                 .map_err(|e| format!(
                     "unable to bind on https port: {} error: {}",
-                    *config::HTTPS_PORT,
-                    e
+                    config.https_port, e
                 ))?
                 .run(),
-            // http
-            HttpServer::new(new_website)
-                .bind((config::IP_BIND.as_str(), *config::HTTP_PORT))
+            // http, only there for the redirects and the ACME challenges
+            HttpServer::new(redirect_to_https)
+                .bind((config.ip_bind.as_str(), config.http_port))
                 .map_err(|e| format!(
                     "unable to bind on http port: {} error: {}",
-                    *config::HTTP_PORT,
-                    e
+                    config.http_port, e
                 ))?
                 .run(),
         )?;
     } else {
         // http only
         HttpServer::new(new_website)
-            .bind((config::IP_BIND.as_str(), *config::HTTP_PORT))
+            .bind((config.ip_bind.as_str(), config.http_port))
             .map_err(|e| {
                 format!(
                     "unable to bind on http port: {} error: {}",
-                    *config::HTTP_PORT,
-                    e
+                    config.http_port, e
                 )
             })?
             .run()
@@ -671,3 +1116,74 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use actix_web::test::{call_service, init_service, TestRequest};
+
+    #[actix_web::test]
+    async fn test_static_files() {
+        let dir = std::env::temp_dir().join(format!("static_files_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("site.css"), "body {}").unwrap();
+
+        let app = init_service(
+            App::new()
+                .configure(static_files(https::ACME_CHALLENGE_PATH, "./does-not-exist"))
+                .configure(static_files("/data", &dir.to_string_lossy()))
+                .default_service(web::to(page_404)),
+        )
+        .await;
+        let get = |uri: &str| TestRequest::get().uri(uri).to_request();
+
+        // files of the working directory
+        for uri in [
+            "/.well-known/acme-challenge/key.pem",
+            "/.well-known/acme-challenge/Cargo.toml",
+            "/.well-known/acme-challenge/src/main.rs",
+        ] {
+            let res = call_service(&app, get(uri)).await;
+            assert_eq!(res.status(), StatusCode::NOT_FOUND, "{}", uri);
+        }
+        let res = call_service(&app, get("/data/site.css")).await;
+        assert_eq!(res.status(), StatusCode::OK);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[actix_web::test]
+    async fn test_unknown_tag_ignores_preconditions() {
+        let dir = std::env::temp_dir().join(format!("tag_preconditions_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(
+            dir.join("hello.md"),
+            "---\nTitle: Hello\nDate: 2023-04-12\nTags: Rust\n---\n",
+        )
+        .unwrap();
+
+        let app = init_service(
+            App::new()
+                .app_data(web::Data::new(ArticleIndex::new(&dir, false)))
+                .service(tagged_articles)
+                .service(tagged_articles_paged),
+        )
+        .await;
+        let get = |uri: &str| {
+            TestRequest::get()
+                .uri(uri)
+                .insert_header((header::IF_NONE_MATCH, "*"))
+                .to_request()
+        };
+
+        let res = call_service(&app, get("/tags/rust")).await;
+        assert_eq!(res.status(), StatusCode::NOT_MODIFIED);
+        let res = call_service(&app, get("/tags/does-not-exist")).await;
+        assert_eq!(res.status(), StatusCode::NOT_FOUND);
+        let res = call_service(&app, get("/tags/does-not-exist/page/1")).await;
+        assert_eq!(res.status(), StatusCode::NOT_FOUND);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -1,27 +1,144 @@
+use crate::{highlight, toc};
+
 use std::collections::BTreeMap;
 use std::io::{self, BufRead};
 
-pub fn md_to_html(s: &str) -> String {
-    markdown::to_html_with_options(
+fn parse_options() -> markdown::ParseOptions {
+    markdown::ParseOptions {
+        gfm_strikethrough_single_tilde: true,
+        constructs: markdown::Constructs {
+            autolink: true,
+            character_escape: true,
+            gfm_footnote_definition: true,
+            gfm_label_start_footnote: true,
+            gfm_strikethrough: true,
+            gfm_table: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+/// the html along with the headings it contains
+pub fn md_to_html(s: &str) -> (String, Vec<toc::Heading>) {
+    let html = markdown::to_html_with_options(
         s,
         &markdown::Options {
-            parse: markdown::ParseOptions {
-                gfm_strikethrough_single_tilde: true,
-                constructs: markdown::Constructs {
-                    autolink: true,
-                    character_escape: true,
-                    gfm_footnote_definition: true,
-                    gfm_label_start_footnote: true,
-                    gfm_strikethrough: true,
-                    gfm_table: true,
-                    ..Default::default()
-                },
-                ..Default::default()
-            },
+            parse: parse_options(),
             compile: markdown::CompileOptions::gfm(),
         },
     )
-    .unwrap()
+    .unwrap();
+    toc::anchor_headings(&highlight::highlight_code_blocks(&html))
+}
+
+/// the prose without its markup, code blocks and raw html are left out
+pub fn plain_text(s: &str) -> String {
+    fn collect(node: &markdown::mdast::Node, out: &mut String) {
+        use markdown::mdast::Node;
+        match node {
+            Node::Code(_) | Node::Html(_) => (),
+            Node::Text(v) => out.push_str(&v.value),
+            Node::InlineCode(v) => out.push_str(&v.value),
+            Node::Break(_) => out.push(' '),
+            _ => {
+                for child in node.children().into_iter().flatten() {
+                    collect(child, out);
+                }
+                // keeps the words of two blocks apart
+                if !matches!(
+                    node,
+                    Node::Emphasis(_) | Node::Strong(_) | Node::Delete(_) | Node::Link(_)
+                ) {
+                    out.push(' ');
+                }
+            }
+        }
+    }
+
+    let mut text = String::new();
+    if let Ok(root) = markdown::to_mdast(s, &parse_options()) {
+        collect(&root, &mut text);
+    }
+    text
+}
+
+/// words of the prose, code blocks and raw html are left out
+pub fn word_count(s: &str) -> usize {
+    plain_text(s)
+        .split_whitespace()
+        .filter(|w| w.chars().any(char::is_alphanumeric))
+        .count()
+}
+
+/// makes text safe to put in html, attribute values included
+pub fn escape_html(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// reverses the escaping done by the markdown renderer
+pub fn unescape_html(s: &str) -> String {
+    s.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&amp;", "&")
+}
+
+/// splits a `Tags: Rust, Web, Blog` header value into its tags, dropping
+/// empty entries and duplicates
+pub fn parse_tags(s: &str) -> Vec<String> {
+    let mut tags: Vec<String> = Vec::new();
+    for tag in s.split(',').map(str::trim).filter(|t| !t.is_empty()) {
+        if !tags.iter().any(|t| slugify(t) == slugify(tag)) {
+            tags.push(tag.to_string());
+        }
+    }
+    tags
+}
+
+/// splits an `Aliases: old-slug, /some/old/path` header value into absolute
+/// paths, bare names are old slugs of the article: `/article/<name>`
+pub fn parse_aliases(s: &str) -> Vec<String> {
+    s.split(',')
+        .map(str::trim)
+        .filter(|a| !a.is_empty())
+        .map(|a| {
+            if a.starts_with('/') {
+                normalize_path(a)
+            } else {
+                normalize_path(&format!("/article/{}", a))
+            }
+        })
+        .collect()
+}
+
+/// `/foo/` and `/foo` are the same page
+pub fn normalize_path(path: &str) -> String {
+    match path.trim_end_matches('/') {
+        "" => "/".to_string(),
+        v => v.to_string(),
+    }
+}
+
+/// url friendly version of a tag or name, `Rust` and `rust ` share the same slug
+pub fn slugify(s: &str) -> String {
+    let mut slug = String::with_capacity(s.len());
+    for c in s.trim().chars().flat_map(char::to_lowercase) {
+        if c.is_alphanumeric() {
+            slug.push(c);
+        } else if c == '+' {
+            // keeps `C` and `C++` apart
+            slug.push('p');
+        } else if c == '#' {
+            slug.push('s');
+        } else if !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    slug.trim_matches('-').to_string()
 }
 
 #[derive(Debug)]
@@ -104,8 +221,34 @@ impl ExtendedMd {
         })
     }
 
+    pub fn word_count(&self) -> usize {
+        word_count(&self.markdown_str)
+    }
+
+    pub fn plain_text(&self) -> String {
+        plain_text(&self.markdown_str)
+    }
+
+    /// with `Toc: true` in the header, the table of contents replaces the
+    /// `[[toc]]` marker, or follows the title when there is no marker
     pub fn to_html(&self) -> String {
-        md_to_html(&self.markdown_str)
+        let (html, headings) = md_to_html(&self.markdown_str);
+        let toc = match self.header.get("Toc") {
+            Some(v) if v.eq_ignore_ascii_case("true") => toc::toc_html(&headings),
+            _ => String::new(),
+        };
+
+        if html.contains(toc::MARKER) {
+            html.replacen(toc::MARKER, &toc, 1)
+        } else if toc.is_empty() {
+            html
+        } else {
+            let at = match html.find("</h1>") {
+                Some(i) if html.starts_with("<h1") => i + "</h1>".len(),
+                _ => 0,
+            };
+            format!("{}{}{}", &html[..at], toc, &html[at..])
+        }
     }
 }
 
@@ -145,4 +288,62 @@ More content
             }
         );
     }
+
+    #[test]
+    fn test_toc() {
+        use std::io::Cursor;
+        let md = |s: &str| ExtendedMd::from_bufread(Cursor::new(s.as_bytes())).unwrap();
+
+        let html = md("---\nToc: true\n---\n# Title\n\n## Part\n").to_html();
+        assert!(html.starts_with("<h1 id=\"title\">"));
+        assert!(html.contains("</h1><nav class=\"toc\">"));
+
+        let html = md("---\nToc: true\n---\n# Title\n\nintro\n\n[[toc]]\n\n## Part\n").to_html();
+        assert!(html.contains("<p>intro</p>\n<nav class=\"toc\">"));
+
+        let html = md("---\nTitle: a\n---\n[[toc]]\n\n## Part\n").to_html();
+        assert!(!html.contains("toc"));
+    }
+
+    #[test]
+    fn test_word_count() {
+        assert_eq!(
+            word_count("# Hello *world*\n\nIt's `a` test - right?\n\n```rust\nfn main() {}\n```\n"),
+            6
+        );
+        assert_eq!(word_count(""), 0);
+        assert_eq!(
+            plain_text("# Title\n\nsome **bold**ness\n\n- a\n- b\n")
+                .split_whitespace()
+                .collect::<Vec<_>>(),
+            vec!["Title", "some", "boldness", "a", "b"]
+        );
+        assert_eq!(
+            escape_html("<a href=\"&\">"),
+            "&lt;a href=&quot;&amp;&quot;&gt;"
+        );
+    }
+
+    #[test]
+    fn test_parse_tags() {
+        assert_eq!(
+            parse_tags("Rust, Web,, rust ,Blog post"),
+            vec!["Rust", "Web", "Blog post"]
+        );
+        assert_eq!(slugify(" Blog post "), "blog-post");
+        assert_eq!(slugify("C++"), "cpp");
+        assert_eq!(slugify("C#"), "cs");
+    }
+
+    #[test]
+    fn test_parse_aliases() {
+        assert_eq!(
+            parse_aliases("old-name, /blog/old/ ,, /"),
+            vec!["/article/old-name", "/blog/old", "/"]
+        );
+        assert_eq!(
+            parse_aliases("old-name/, other//"),
+            vec!["/article/old-name", "/article/other"]
+        );
+    }
 }
@@ -0,0 +1,188 @@
+//! Standalone markdown pages, every `<slug>.md` of the pages directory is
+//! served at `/<slug>`. The directory is read once at startup.
+//!
+//! Besides `Title`, the header of a page can have:
+//! - `Nav`: label of the page's link in the header, left out of it otherwise
+//! - `Nav-Order`: links with a lower order come first, defaults to 0
+
+use crate::md_ex::{ExtendedMd, HeaderError};
+
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::{self, BufReader};
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+static PAGES: OnceLock<Pages> = OnceLock::new();
+
+/// `/<slug>` of these is already a route, such a page could never be reached
+const RESERVED_SLUGS: [&str; 11] = [
+    "articles",
+    "tags",
+    "search",
+    "rss",
+    "rss.xml",
+    "atom.xml",
+    "feed.json",
+    "sitemap.xml",
+    "highlight.css",
+    "data",
+    "media",
+];
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Page {
+    /// file name without the `.md` extension
+    pub slug: String,
+    pub path: PathBuf,
+    pub title: String,
+    pub nav: Option<String>,
+    pub nav_order: i32,
+}
+
+impl Page {
+    fn from_header(path: &Path, header: &BTreeMap<String, String>) -> Self {
+        let slug = path
+            .file_stem()
+            .map(|v| v.to_string_lossy().to_string())
+            .unwrap_or_default();
+
+        let nav_order = match header.get("Nav-Order").map(|v| v.parse()) {
+            Some(Ok(v)) => v,
+            Some(Err(e)) => {
+                log::warn!("Invalid Nav-Order in '{}': {e}", path.display());
+                0
+            }
+            None => 0,
+        };
+
+        Self {
+            title: header.get("Title").cloned().unwrap_or_else(|| slug.clone()),
+            slug,
+            path: path.to_path_buf(),
+            nav: header.get("Nav").cloned(),
+            nav_order,
+        }
+    }
+
+    /// reads only the header of the file at `path`
+    fn from_file(path: &Path) -> Result<Self, HeaderError> {
+        let file = BufReader::new(File::open(path)?);
+        let header = ExtendedMd::read_header(file)?;
+        Ok(Self::from_header(path, &header))
+    }
+}
+
+/// sorted by `nav_order`, then by slug
+#[derive(Default, Debug)]
+pub struct Pages(Vec<Page>);
+
+impl Pages {
+    /// invalid pages and the ones shadowed by a route are logged and left
+    /// out, a missing directory means no pages
+    pub fn load(dir: &Path) -> io::Result<Self> {
+        let entries = match std::fs::read_dir(dir) {
+            Ok(v) => v,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                log::warn!("No pages directory at '{}'", dir.display());
+                return Ok(Self::default());
+            }
+            Err(e) => return Err(e),
+        };
+
+        let mut pages = Vec::new();
+        for entry in entries {
+            let entry = entry?;
+            let name = entry.file_name().to_string_lossy().to_lowercase();
+            if !entry.metadata()?.is_file() || name.starts_with('.') || !name.ends_with(".md") {
+                continue;
+            }
+
+            let path = entry.path();
+            match Page::from_file(&path) {
+                Ok(page) if RESERVED_SLUGS.contains(&page.slug.as_str()) => log::error!(
+                    "Invalid page '{}': '/{}' is reserved",
+                    path.display(),
+                    page.slug
+                ),
+                Ok(page) => pages.push(page),
+                Err(e) => log::error!("Invalid page '{}': {e}", path.display()),
+            }
+        }
+
+        pages.sort_unstable_by(|s, o| {
+            s.nav_order
+                .cmp(&o.nav_order)
+                .then_with(|| s.slug.cmp(&o.slug))
+        });
+        Ok(Self(pages))
+    }
+
+    pub fn find(&self, slug: &str) -> Option<&Page> {
+        self.0.iter().find(|page| page.slug == slug)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Page> {
+        self.0.iter()
+    }
+
+    /// pages linked from the header, in order
+    pub fn nav(&self) -> impl Iterator<Item = (&str, &Page)> {
+        self.0
+            .iter()
+            .filter_map(|page| page.nav.as_deref().map(|label| (label, page)))
+    }
+}
+
+/// has to be called once, before anything renders a page
+pub fn init(pages: Pages) {
+    if PAGES.set(pages).is_err() {
+        log::warn!("Pages were already loaded, ignoring the new ones");
+    }
+}
+
+pub fn get() -> &'static Pages {
+    PAGES.get_or_init(Pages::default)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_page_from_header() {
+        let header: BTreeMap<String, String> = [
+            ("Title", "Data Policy"),
+            ("Nav", "data policy"),
+            ("Nav-Order", "2"),
+        ]
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect();
+        let page = Page::from_header(Path::new("pages/data-policy.md"), &header);
+        assert_eq!(page.slug, "data-policy");
+        assert_eq!(page.title, "Data Policy");
+        assert_eq!(page.nav.as_deref(), Some("data policy"));
+        assert_eq!(page.nav_order, 2);
+
+        let page = Page::from_header(Path::new("pages/about.md"), &BTreeMap::new());
+        assert_eq!(page.slug, "about");
+        assert_eq!(page.title, "about");
+        assert_eq!(page.nav, None);
+        assert_eq!(page.nav_order, 0);
+    }
+
+    #[test]
+    fn test_load_skips_reserved() {
+        let dir = std::env::temp_dir().join(format!("pages_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("about.md"), "---\nTitle: About\n---\nhello\n").unwrap();
+        std::fs::write(dir.join("tags.md"), "---\nTitle: Tags\n---\nhello\n").unwrap();
+        std::fs::write(dir.join("rss.xml.md"), "---\nTitle: Feed\n---\nhello\n").unwrap();
+
+        let pages = Pages::load(&dir).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+        let slugs: Vec<&str> = pages.iter().map(|page| page.slug.as_str()).collect();
+        assert_eq!(slugs, ["about"]);
+    }
+}
@@ -0,0 +1,440 @@
+use crate::md_ex::{self, ExtendedMd, HeaderError};
+
+use std::collections::{BTreeMap, HashMap};
+use std::fs::File;
+use std::io::{self, BufReader};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use time::format_description::well_known::Rfc3339;
+use time::macros::format_description;
+use time::{Date, OffsetDateTime};
+
+#[derive(Debug)]
+pub enum ArticleError {
+    MissingKey {
+        key: &'static str,
+    },
+    InvalidDate {
+        value: String,
+        error: time::error::Parse,
+    },
+    /// the slug, given or derived from the file name, has no usable character
+    InvalidSlug {
+        value: String,
+    },
+    DuplicateSlug {
+        slug: String,
+    },
+    Header(HeaderError),
+}
+
+impl std::fmt::Display for ArticleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ArticleError::MissingKey { key } => {
+                f.write_str(&format!("Missing required header key '{}'", key))
+            }
+            ArticleError::InvalidDate { value, error } => f.write_str(&format!(
+                "Invalid date '{}', expected YYYY-MM-DD or YYYY-MM-DD HH:MM +HH:MM: {}",
+                value, error
+            )),
+            ArticleError::InvalidSlug { value } => {
+                f.write_str(&format!("Unable to make a slug out of '{}'", value))
+            }
+            ArticleError::DuplicateSlug { slug } => f.write_str(&format!(
+                "Slug '{}' is already used by another article",
+                slug
+            )),
+            ArticleError::Header(e) => e.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for ArticleError {}
+
+impl From<HeaderError> for ArticleError {
+    fn from(e: HeaderError) -> Self {
+        ArticleError::Header(e)
+    }
+}
+
+impl From<io::Error> for ArticleError {
+    fn from(e: io::Error) -> Self {
+        ArticleError::Header(HeaderError::IO(e))
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Article {
+    /// name used to reach the article from its url, either the `Slug` header
+    /// or derived from the file name
+    pub slug: String,
+    /// the article used to be served under its file name, `/article/<file_name>`
+    /// redirects to the slug
+    pub file_name: String,
+    pub path: PathBuf,
+    pub title: String,
+    pub author: Option<String>,
+    /// publication time, the article is hidden until then
+    pub date: OffsetDateTime,
+    /// last revision, never before `date`, see `CommitTimes::last_modified`
+    /// when the header doesn't have an `Updated` key
+    pub updated: OffsetDateTime,
+    pub blurb: Option<String>,
+    pub tags: Vec<String>,
+    /// old paths of the article, they redirect to it
+    pub aliases: Vec<String>,
+    pub draft: bool,
+    /// only known once the markdown is read, code blocks are not counted
+    pub word_count: usize,
+}
+
+impl Article {
+    pub fn from_header(
+        path: &Path,
+        header: &BTreeMap<String, String>,
+    ) -> Result<Self, ArticleError> {
+        let file_name = path
+            .file_name()
+            .map(|v| v.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let draft = file_name.to_lowercase().ends_with(".wip");
+
+        let slug_source = match header.get("Slug") {
+            Some(v) => v.as_str(),
+            None => strip_extension(&file_name),
+        };
+        let slug = md_ex::slugify(slug_source);
+        if slug.is_empty() {
+            return Err(ArticleError::InvalidSlug {
+                value: slug_source.to_string(),
+            });
+        }
+
+        let title = header
+            .get("Title")
+            .cloned()
+            .ok_or(ArticleError::MissingKey { key: "Title" })?;
+
+        let date_str = header
+            .get("Date")
+            .ok_or(ArticleError::MissingKey { key: "Date" })?;
+        let date = parse_date(date_str).map_err(|error| ArticleError::InvalidDate {
+            value: date_str.clone(),
+            error,
+        })?;
+        let updated = match header.get("Updated") {
+            Some(v) => parse_date(v).map_err(|error| ArticleError::InvalidDate {
+                value: v.clone(),
+                error,
+            })?,
+            None => date,
+        };
+
+        let tags = header
+            .get("Tags")
+            .map(|v| md_ex::parse_tags(v))
+            .unwrap_or_default();
+
+        Ok(Self {
+            slug,
+            file_name,
+            path: path.to_path_buf(),
+            title,
+            author: header.get("Author").cloned(),
+            date,
+            updated: updated.max(date),
+            blurb: header.get("Blurb").cloned(),
+            tags,
+            aliases: header
+                .get("Aliases")
+                .map(|v| md_ex::parse_aliases(v))
+                .unwrap_or_default(),
+            draft,
+            word_count: 0,
+        })
+    }
+
+    /// reads only the header of the file at `path`, `commits` are those of
+    /// its directory
+    pub fn from_file(path: &Path, commits: &CommitTimes) -> Result<Self, ArticleError> {
+        let file = BufReader::new(File::open(path)?);
+        let header = ExtendedMd::read_header(file)?;
+        let mut post = Self::from_header(path, &header)?;
+        if !header.contains_key("Updated") {
+            if let Some(v) = commits.last_modified(path) {
+                post.updated = v.max(post.date);
+            }
+        }
+        Ok(post)
+    }
+
+    /// revised after the day it was published
+    pub fn was_updated(&self) -> bool {
+        self.updated.date() > self.date.date()
+    }
+
+    /// articles dated in the future are scheduled, not published
+    pub fn is_published(&self, now: OffsetDateTime) -> bool {
+        self.date <= now
+    }
+
+    pub fn has_tag(&self, slug: &str) -> bool {
+        self.tags.iter().any(|t| md_ex::slugify(t) == slug)
+    }
+
+    pub fn has_author(&self, slug: &str) -> bool {
+        self.author
+            .as_deref()
+            .is_some_and(|author| md_ex::slugify(author) == slug)
+    }
+
+    /// estimated time to read the article, at least a minute
+    pub fn reading_minutes(&self, words_per_minute: u32) -> usize {
+        self.word_count
+            .div_ceil(words_per_minute.max(1) as usize)
+            .max(1)
+    }
+
+    /// reads the whole article from disk, header included
+    pub fn read_markdown(&self) -> Result<ExtendedMd, HeaderError> {
+        let file = File::open(&self.path)?;
+        ExtendedMd::from_bufread(BufReader::new(file))
+    }
+}
+
+/// a bare date is midnight UTC, times need an offset: either
+/// `2023-04-12 09:30 +02:00` or RFC 3339 (`2023-04-12T09:30:00+02:00`)
+fn parse_date(value: &str) -> Result<OffsetDateTime, time::error::Parse> {
+    if let Ok(date) = Date::parse(value, format_description!("[year]-[month]-[day]")) {
+        return Ok(date.midnight().assume_utc());
+    }
+    OffsetDateTime::parse(value, &Rfc3339).or_else(|_| {
+        OffsetDateTime::parse(
+            value,
+            format_description!(
+                "[year]-[month]-[day] [hour]:[minute] [offset_hour sign:mandatory]:[offset_minute]"
+            ),
+        )
+    })
+}
+
+/// time of the last commit touching each file of a directory, by file name
+#[derive(Default, Debug)]
+pub struct CommitTimes(HashMap<String, OffsetDateTime>);
+
+impl CommitTimes {
+    /// a single `git log` for the whole directory, empty when it isn't part
+    /// of a repository
+    pub fn load(dir: &Path) -> Self {
+        let output = Command::new("git")
+            // keeps non ascii file names as is
+            .args(["-c", "core.quotePath=false", "log"])
+            .args(["--relative", "--name-only", "--format=%cI", "--", "."])
+            .current_dir(dir)
+            .output();
+        match output {
+            Ok(output) if output.status.success() => {
+                Self::parse(&String::from_utf8_lossy(&output.stdout))
+            }
+            _ => Self::default(),
+        }
+    }
+
+    /// commits come from the most recent to the oldest, each is its time
+    /// followed by the files it touched
+    fn parse(log: &str) -> Self {
+        let mut times = HashMap::new();
+        let mut current = None;
+        for line in log.lines().filter(|line| !line.is_empty()) {
+            match OffsetDateTime::parse(line, &Rfc3339) {
+                Ok(time) => current = Some(time),
+                Err(_) => {
+                    if let Some(time) = current {
+                        times.entry(line.to_string()).or_insert(time);
+                    }
+                }
+            }
+        }
+        Self(times)
+    }
+
+    /// time of the last commit touching the file or, when it isn't tracked
+    /// by git, its mtime; checkouts reset the mtime so git is asked first
+    pub fn last_modified(&self, path: &Path) -> Option<OffsetDateTime> {
+        let file_name = path.file_name()?.to_string_lossy();
+        self.0.get(file_name.as_ref()).copied().or_else(|| {
+            let mtime = std::fs::metadata(path).ok()?.modified().ok()?;
+            Some(OffsetDateTime::from(mtime))
+        })
+    }
+}
+
+/// `foo.md.wip` -> `foo`
+fn strip_extension(file_name: &str) -> &str {
+    let lower = file_name.to_lowercase();
+    [".md.wip", ".md"]
+        .iter()
+        .find(|ext| lower.ends_with(*ext))
+        .map(|ext| &file_name[..file_name.len() - ext.len()])
+        .unwrap_or(file_name)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn header(pairs: &[(&str, &str)]) -> BTreeMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_from_header() {
+        let article = Article::from_header(
+            Path::new("./articles/hello.md.wip"),
+            &header(&[
+                ("Title", "Hello"),
+                ("Date", "2023-04-12"),
+                ("Tags", "Rust, Web,, Blog "),
+            ]),
+        )
+        .unwrap();
+        assert_eq!(article.slug, "hello");
+        assert_eq!(article.file_name, "hello.md.wip");
+        assert_eq!(article.title, "Hello");
+        assert_eq!(article.date, time::macros::datetime!(2023-04-12 0:00 UTC));
+        assert_eq!(article.tags, vec!["Rust", "Web", "Blog"]);
+        assert!(article.draft);
+        assert_eq!(article.author, None);
+    }
+
+    #[test]
+    fn test_reading_minutes() {
+        let mut article = Article::from_header(
+            Path::new("hello.md"),
+            &header(&[("Title", "Hello"), ("Date", "2023-04-12")]),
+        )
+        .unwrap();
+        assert_eq!(article.reading_minutes(200), 1);
+        article.word_count = 401;
+        assert_eq!(article.reading_minutes(200), 3);
+        assert_eq!(article.reading_minutes(0), 401);
+    }
+
+    #[test]
+    fn test_invalid_header() {
+        let path = Path::new("hello.md");
+        assert!(matches!(
+            Article::from_header(path, &header(&[("Date", "2023-04-12")])),
+            Err(ArticleError::MissingKey { key: "Title" })
+        ));
+        assert!(matches!(
+            Article::from_header(path, &header(&[("Title", "Hello"), ("Date", "2023-13-01")])),
+            Err(ArticleError::InvalidDate { .. })
+        ));
+        assert!(matches!(
+            Article::from_header(
+                path,
+                &header(&[("Title", "Hello"), ("Date", "2023-04-12"), ("Slug", " -- ")])
+            ),
+            Err(ArticleError::InvalidSlug { .. })
+        ));
+    }
+
+    #[test]
+    fn test_parse_date() {
+        use time::macros::datetime;
+
+        let expected = datetime!(2023-04-12 9:30 +2);
+        assert_eq!(parse_date("2023-04-12 09:30 +02:00").unwrap(), expected);
+        assert_eq!(parse_date("2023-04-12T09:30:00+02:00").unwrap(), expected);
+        assert_eq!(parse_date("2023-04-12T07:30:00Z").unwrap(), expected);
+        // an offset is required, the server's timezone would be a guess
+        assert!(parse_date("2023-04-12 09:30").is_err());
+        assert!(parse_date("12/04/2023").is_err());
+
+        let article = Article::from_header(
+            Path::new("hello.md"),
+            &header(&[("Title", "Hello"), ("Date", "2023-04-12 09:30 +02:00")]),
+        )
+        .unwrap();
+        assert!(!article.is_published(datetime!(2023-04-12 7:29 UTC)));
+        assert!(article.is_published(datetime!(2023-04-12 7:30 UTC)));
+        assert_eq!(article.updated, article.date);
+        assert!(!article.was_updated());
+
+        let article = Article::from_header(
+            Path::new("hello.md"),
+            &header(&[
+                ("Title", "Hello"),
+                ("Date", "2023-04-12"),
+                ("Updated", "2023-05-01"),
+            ]),
+        )
+        .unwrap();
+        assert_eq!(article.updated, datetime!(2023-05-01 0:00 UTC));
+        assert!(article.was_updated());
+    }
+
+    #[test]
+    fn test_commit_times() {
+        use time::macros::datetime;
+
+        let commits = CommitTimes::parse(
+            "2023-05-01T10:00:00+02:00\n\nhello.md\nnew.md\n\
+             2023-04-12T09:30:00+02:00\n\nhello.md\nold.md\n",
+        );
+        let dir = std::env::temp_dir().join(format!("commit_times_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        for name in ["hello.md", "old.md", "untracked.md"] {
+            std::fs::write(dir.join(name), "").unwrap();
+        }
+        let mtime = OffsetDateTime::from(
+            std::fs::metadata(dir.join("untracked.md"))
+                .unwrap()
+                .modified()
+                .unwrap(),
+        );
+
+        assert_eq!(
+            commits.last_modified(&dir.join("hello.md")),
+            Some(datetime!(2023-05-01 10:00 +2))
+        );
+        assert_eq!(
+            commits.last_modified(&dir.join("old.md")),
+            Some(datetime!(2023-04-12 9:30 +2))
+        );
+        assert_eq!(
+            commits.last_modified(&dir.join("untracked.md")),
+            Some(mtime)
+        );
+        assert_eq!(commits.last_modified(&dir.join("missing.md")), None);
+        // not a repository, every file falls back to its mtime
+        assert!(CommitTimes::load(&dir).0.is_empty());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_slug() {
+        let date = ("Date", "2023-04-12");
+        let article = Article::from_header(
+            Path::new("./articles/how_I_do_container_types_in_C.md"),
+            &header(&[("Title", "Hello"), date]),
+        )
+        .unwrap();
+        assert_eq!(article.slug, "how-i-do-container-types-in-c");
+
+        let article = Article::from_header(
+            Path::new("./articles/hello.MD"),
+            &header(&[("Title", "Hello"), date, ("Slug", "Hello World")]),
+        )
+        .unwrap();
+        assert_eq!(article.slug, "hello-world");
+        assert_eq!(article.file_name, "hello.MD");
+    }
+}
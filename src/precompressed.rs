@@ -0,0 +1,173 @@
+//! Static files are served from a `.br` or `.gz` sibling (`site.css.br` for
+//! `site.css`) when the client accepts that encoding, `website build` writes
+//! them. Anything else is compressed on the fly by the `Compress` middleware.
+
+use crate::config;
+
+use actix_files::NamedFile;
+use actix_web::http::header::{self, AcceptEncoding, ContentEncoding, Encoding, HeaderValue};
+use actix_web::http::Method;
+use actix_web::{HttpMessage, HttpRequest, HttpResponse};
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// preferred first, the extension of a sibling along with its encoding
+pub const ENCODINGS: [(&str, ContentEncoding); 2] = [
+    ("br", ContentEncoding::Brotli),
+    ("gz", ContentEncoding::Gzip),
+];
+
+/// the precompressed sibling of the static file `req` asks for, if there is
+/// one the client accepts
+pub fn respond(req: &HttpRequest) -> Option<HttpResponse> {
+    if req.method() != Method::GET && req.method() != Method::HEAD {
+        return None;
+    }
+    serve(req, &static_path(req.path())?)
+}
+
+/// the sibling of the file at `path` the client prefers
+fn serve(req: &HttpRequest, path: &Path) -> Option<HttpResponse> {
+    let accept = req.get_header::<AcceptEncoding>()?;
+    let modified = fs::metadata(path).ok()?.modified().ok()?;
+
+    // a sibling older than the file is left over from a previous version
+    let siblings: Vec<(PathBuf, ContentEncoding)> = ENCODINGS
+        .iter()
+        .map(|(ext, encoding)| (sibling(path, ext), *encoding))
+        .filter(|(sibling, _)| {
+            fs::metadata(sibling)
+                .and_then(|m| m.modified())
+                .is_ok_and(|v| v >= modified)
+        })
+        .collect();
+    let supported: Vec<Encoding> = siblings
+        .iter()
+        .map(|(_, encoding)| Encoding::Known(*encoding))
+        .chain([Encoding::identity()])
+        .collect();
+
+    let Encoding::Known(chosen) = accept.negotiate(supported.iter())? else {
+        return None;
+    };
+    let (sibling, encoding) = siblings.into_iter().find(|(_, v)| *v == chosen)?;
+
+    let file = open(path, &sibling, encoding)
+        .map_err(|e| log::warn!("Unable to serve '{}': {e}", sibling.display()))
+        .ok()?;
+    let mut res = file.into_response(req);
+    res.headers_mut()
+        .append(header::VARY, HeaderValue::from_static("accept-encoding"));
+    Some(res)
+}
+
+/// served with the type of the uncompressed file rather than the sibling's
+fn open(path: &Path, sibling: &Path, encoding: ContentEncoding) -> io::Result<NamedFile> {
+    let content_type = NamedFile::open(path)?.content_type().clone();
+    Ok(NamedFile::open(sibling)?
+        .set_content_type(content_type)
+        .set_content_encoding(encoding)
+        .disable_content_disposition())
+}
+
+/// `foo.css` -> `foo.css.br`
+pub fn sibling(path: &Path, ext: &str) -> PathBuf {
+    let mut name = path.as_os_str().to_os_string();
+    name.push(".");
+    name.push(ext);
+    PathBuf::from(name)
+}
+
+/// where the file behind `/data/...` or `/media/...` lives, only plain paths
+/// are handled, the file services take care of the others
+fn static_path(url: &str) -> Option<PathBuf> {
+    let config = config::get();
+    let (dir, rest) = if let Some(rest) = url.strip_prefix("/data/") {
+        (config.fs_data_path.as_str(), rest)
+    } else if let Some(rest) = url.strip_prefix("/media/") {
+        (config.fs_media_path.as_str(), rest)
+    } else {
+        return None;
+    };
+
+    let plain = rest.split('/').all(|segment| {
+        !segment.is_empty() && !segment.starts_with('.') && !segment.contains(['%', '\\'])
+    });
+    if !plain {
+        return None;
+    }
+    Some(Path::new(dir).join(rest))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use actix_web::test::TestRequest;
+
+    use std::time::{Duration, SystemTime};
+
+    #[test]
+    fn test_serve() {
+        let dir = std::env::temp_dir().join(format!("precompressed_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("site.css");
+        std::fs::write(&path, "body {}").unwrap();
+        std::fs::write(sibling(&path, "br"), "brotli").unwrap();
+        std::fs::write(sibling(&path, "gz"), "gzip").unwrap();
+        let get = |accept: &str| {
+            TestRequest::get()
+                .insert_header((header::ACCEPT_ENCODING, accept))
+                .to_http_request()
+        };
+        let encoding = |res: &HttpResponse| {
+            let content_type = res.headers().get(header::CONTENT_TYPE).unwrap();
+            assert!(content_type.to_str().unwrap().starts_with("text/css"));
+            res.headers().get(header::CONTENT_ENCODING).unwrap().clone()
+        };
+
+        let res = serve(&get("gzip, deflate, br"), &path).unwrap();
+        assert_eq!(encoding(&res), "br");
+        assert_eq!(res.headers().get(header::VARY).unwrap(), "accept-encoding");
+        let res = serve(&get("br;q=0.5, gzip"), &path).unwrap();
+        assert_eq!(encoding(&res), "gzip");
+        assert!(serve(&get("identity"), &path).is_none());
+        assert!(serve(&TestRequest::get().to_http_request(), &path).is_none());
+
+        // left over from a previous version of the file
+        let old = SystemTime::now() - Duration::from_secs(3600);
+        std::fs::File::options()
+            .write(true)
+            .open(sibling(&path, "br"))
+            .unwrap()
+            .set_modified(old)
+            .unwrap();
+        let res = serve(&get("gzip, br"), &path).unwrap();
+        assert_eq!(encoding(&res), "gzip");
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_static_path() {
+        assert_eq!(
+            static_path("/data/site.css"),
+            Some(Path::new("./data").join("site.css"))
+        );
+        assert_eq!(
+            static_path("/media/img/a.png"),
+            Some(Path::new("./media").join("img/a.png"))
+        );
+        assert_eq!(static_path("/article/foo"), None);
+        assert_eq!(static_path("/data/../secret"), None);
+        assert_eq!(static_path("/data/%2e%2e/secret"), None);
+        assert_eq!(static_path("/data//etc/passwd"), None);
+        assert_eq!(static_path("/data/"), None);
+        assert_eq!(
+            sibling(Path::new("./data/site.css"), "br"),
+            Path::new("./data/site.css.br")
+        );
+    }
+}
@@ -0,0 +1,65 @@
+//! Drafts and scheduled articles are not listed, they can only be read at
+//! `/preview/<token>` where the token is an HMAC of the article's slug keyed
+//! with `preview_secret`. Sharing a link exposes that single article,
+//! changing the secret revokes every link at once.
+
+use crate::article_index::Articles;
+use crate::post::Article;
+
+use openssl::hash::MessageDigest;
+use openssl::pkey::PKey;
+use openssl::sign::Signer;
+
+/// bytes of the HMAC kept in the token, 128 bits can't be guessed
+const TOKEN_BYTES: usize = 16;
+
+/// hex encoded token of the article `slug`
+pub fn token(secret: &str, slug: &str) -> String {
+    let mac = PKey::hmac(secret.as_bytes())
+        .and_then(|key| {
+            let mut signer = Signer::new(MessageDigest::sha256(), &key)?;
+            signer.update(slug.as_bytes())?;
+            signer.sign_to_vec()
+        })
+        // only fails when openssl itself is broken
+        .expect("unable to compute an HMAC");
+
+    mac[..TOKEN_BYTES]
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+/// constant time, the comparison doesn't tell how much of a guess was right
+fn verify(secret: &str, slug: &str, token_: &str) -> bool {
+    let expected = token(secret, slug);
+    expected.len() == token_.len() && openssl::memcmp::eq(expected.as_bytes(), token_.as_bytes())
+}
+
+/// the article `token_` was made for, links keep working once it is published
+pub fn find<'a>(articles: &'a Articles, secret: &str, token_: &str) -> Option<&'a Article> {
+    articles
+        .unlisted
+        .iter()
+        .chain(articles.posts.iter())
+        .find(|post| verify(secret, &post.slug, token_))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_token() {
+        let a = token("secret", "draft");
+        assert_eq!(a.len(), TOKEN_BYTES * 2);
+        assert_eq!(a, token("secret", "draft"));
+        assert_ne!(a, token("secret", "other_draft"));
+        assert_ne!(a, token("another secret", "draft"));
+
+        assert!(verify("secret", "draft", &a));
+        assert!(!verify("secret", "draft", &a[1..]));
+        assert!(!verify("secret", "draft", ""));
+        assert!(!verify("secret", "other_draft", &a));
+    }
+}
@@ -0,0 +1,100 @@
+//! Site wide redirects, read once at startup from a toml file mapping old
+//! paths to their new location:
+//!
+//! ```toml
+//! "/blog/old-post.html" = "/article/new-post"
+//! "/feed" = "/rss"
+//! ```
+//!
+//! They, along with the `Aliases` of the articles, are only looked up when
+//! nothing else matched the request.
+
+use crate::article_index::Articles;
+use crate::config::ConfigError;
+use crate::md_ex;
+
+use std::collections::BTreeMap;
+use std::io;
+use std::path::Path;
+use std::sync::OnceLock;
+
+static REDIRECTS: OnceLock<Redirects> = OnceLock::new();
+
+/// old path -> new location
+#[derive(Default, Debug)]
+pub struct Redirects(BTreeMap<String, String>);
+
+impl Redirects {
+    /// it is fine for the file not to exist
+    pub fn load(path: &Path) -> Result<Self, ConfigError> {
+        match std::fs::read_to_string(path) {
+            Ok(v) => Self::parse(&v).map_err(|error| ConfigError::Parse {
+                path: path.to_path_buf(),
+                error,
+            }),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(error) => Err(ConfigError::IO {
+                path: path.to_path_buf(),
+                error,
+            }),
+        }
+    }
+
+    fn parse(s: &str) -> Result<Self, toml::de::Error> {
+        let redirects: BTreeMap<String, String> = toml::from_str(s)?;
+        Ok(Self(
+            redirects
+                .into_iter()
+                .map(|(from, to)| (md_ex::normalize_path(&from), to))
+                .collect(),
+        ))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.0.iter().map(|(k, v)| (k.as_str(), v.as_str()))
+    }
+}
+
+/// where a request for `path` should be redirected, the redirects file takes
+/// precedence over the aliases
+pub fn resolve(path: &str, articles: &Articles) -> Option<String> {
+    let path = md_ex::normalize_path(path);
+    if let Some(to) = get().0.get(&path) {
+        return Some(to.clone());
+    }
+    articles
+        .get_by_alias(&path)
+        .map(|post| format!("/article/{}", post.slug))
+}
+
+/// has to be called once, before the server starts
+pub fn init(redirects: Redirects) {
+    if REDIRECTS.set(redirects).is_err() {
+        log::warn!("Redirects were already loaded, ignoring the new ones");
+    }
+}
+
+pub fn get() -> &'static Redirects {
+    REDIRECTS.get_or_init(Redirects::default)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_redirects() {
+        let redirects = Redirects::parse(
+            r#"
+            "/blog/old/" = "/article/new"
+            "/feed" = "/rss"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(
+            redirects.iter().collect::<Vec<_>>(),
+            vec![("/blog/old", "/article/new"), ("/feed", "/rss")]
+        );
+        assert!(Redirects::parse("\"/feed\" = 1").is_err());
+    }
+}
@@ -0,0 +1,171 @@
+//! Full text search over the titles and the prose of the articles, the index
+//! is rebuilt along with the articles
+
+use crate::md_ex;
+
+use std::collections::HashMap;
+
+/// a word of the title weighs as much as this many words of the body
+const TITLE_WEIGHT: f64 = 3.0;
+
+/// length of a snippet, in words
+const SNIPPET_WORDS: usize = 30;
+
+struct Document {
+    slug: String,
+    text: String,
+    words: usize,
+}
+
+#[derive(Default)]
+pub struct SearchIndex {
+    /// term -> (document, weighted number of occurrences)
+    postings: HashMap<String, Vec<(usize, f64)>>,
+    documents: Vec<Document>,
+}
+
+pub struct Hit<'a> {
+    pub slug: &'a str,
+    /// html escaped excerpt around the first match, matches are in `<mark>`
+    pub snippet: String,
+}
+
+/// lowercase words, punctuation splits them
+fn terms(s: &str) -> impl Iterator<Item = String> + '_ {
+    s.split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(str::to_lowercase)
+}
+
+impl SearchIndex {
+    /// `text` is the plain text of the article, as given by `ExtendedMd`
+    pub fn add(&mut self, slug: &str, title: &str, text: String) {
+        let id = self.documents.len();
+        let mut counts: HashMap<String, f64> = HashMap::new();
+        for term in terms(title) {
+            *counts.entry(term).or_default() += TITLE_WEIGHT;
+        }
+        let mut words = 0;
+        for term in terms(&text) {
+            *counts.entry(term).or_default() += 1.0;
+            words += 1;
+        }
+
+        for (term, count) in counts {
+            self.postings.entry(term).or_default().push((id, count));
+        }
+        self.documents.push(Document {
+            slug: slug.to_string(),
+            text,
+            words,
+        });
+    }
+
+    /// best matches first, ranked by tf-idf; articles missing some of the
+    /// terms rank lower than the ones containing all of them
+    pub fn search(&self, query: &str) -> Vec<Hit<'_>> {
+        let mut query_terms: Vec<String> = terms(query).collect();
+        query_terms.sort_unstable();
+        query_terms.dedup();
+        if query_terms.is_empty() {
+            return Vec::new();
+        }
+
+        let total = self.documents.len() as f64;
+        // document -> (score, number of matched terms)
+        let mut scores: HashMap<usize, (f64, usize)> = HashMap::new();
+        for term in query_terms.iter() {
+            let Some(postings) = self.postings.get(term) else {
+                continue;
+            };
+            let idf = (1.0 + total / postings.len() as f64).ln();
+            for (id, count) in postings.iter() {
+                // long articles would otherwise always win
+                let tf = count / (self.documents[*id].words as f64 + 1.0).sqrt();
+                let score = scores.entry(*id).or_default();
+                score.0 += tf * idf;
+                score.1 += 1;
+            }
+        }
+
+        let mut ranked: Vec<(usize, f64)> = scores
+            .into_iter()
+            .map(|(id, (score, matched))| (id, score * matched as f64 / query_terms.len() as f64))
+            .collect();
+        ranked.sort_unstable_by(|s, o| o.1.total_cmp(&s.1).then_with(|| s.0.cmp(&o.0)));
+
+        ranked
+            .into_iter()
+            .map(|(id, _)| Hit {
+                slug: &self.documents[id].slug,
+                snippet: snippet(&self.documents[id].text, &query_terms),
+            })
+            .collect()
+    }
+}
+
+fn snippet(text: &str, query_terms: &[String]) -> String {
+    let words: Vec<&str> = text.split_whitespace().collect();
+    let is_match = |word: &str| terms(word).any(|t| query_terms.contains(&t));
+
+    let first = words.iter().position(|w| is_match(w)).unwrap_or(0);
+    // show a bit of what comes before the match
+    let start = first.saturating_sub(SNIPPET_WORDS / 3);
+    let end = words.len().min(start + SNIPPET_WORDS);
+
+    let mut out = String::new();
+    if start > 0 {
+        out.push_str("… ");
+    }
+    for (i, word) in words[start..end].iter().enumerate() {
+        if i > 0 {
+            out.push(' ');
+        }
+        if is_match(word) {
+            out.push_str(&format!("<mark>{}</mark>", md_ex::escape_html(word)));
+        } else {
+            out.push_str(&md_ex::escape_html(word));
+        }
+    }
+    if end < words.len() {
+        out.push_str(" …");
+    }
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_search() {
+        let mut index = SearchIndex::default();
+        index.add(
+            "rust",
+            "Building a blog with Rust",
+            "It is <fast> enough".into(),
+        );
+        index.add(
+            "c",
+            "Container types in C",
+            "Rust has generics, C has macros".into(),
+        );
+        index.add("oop", "A bug", "Nothing to see".into());
+
+        let hits = index.search("RUST");
+        let slugs: Vec<_> = hits.iter().map(|h| h.slug).collect();
+        assert_eq!(slugs, vec!["rust", "c"]);
+        assert_eq!(hits[0].snippet, "It is &lt;fast&gt; enough");
+        assert_eq!(
+            hits[1].snippet,
+            "<mark>Rust</mark> has generics, C has macros"
+        );
+
+        // matching every term wins
+        let slugs: Vec<_> = index.search("rust macros").iter().map(|h| h.slug).collect();
+        assert_eq!(slugs, vec!["c", "rust"]);
+
+        assert!(index.search("").is_empty());
+        assert!(index.search("python").is_empty());
+    }
+}
@@ -0,0 +1,99 @@
+//! `/sitemap.xml`, lists the pages search engines should crawl, articles come
+//! with the time of their last revision
+
+use crate::article_index::Articles;
+use crate::feed::rfc3339;
+use crate::pages::Pages;
+
+use quick_xml::events::{BytesDecl, BytesText, Event};
+use quick_xml::Writer;
+
+use std::io;
+
+use time::OffsetDateTime;
+
+pub const CONTENT_TYPE: &str = "application/xml; charset=utf-8";
+
+/// `base_url` is the root of the website, without a trailing slash
+pub fn build(base_url: &str, articles: &Articles, pages: &Pages) -> io::Result<String> {
+    let mut writer = Writer::new_with_indent(Vec::new(), b' ', 2);
+    writer.write_event(Event::Decl(BytesDecl::new("1.0", Some("UTF-8"), None)))?;
+
+    // the listings change whenever an article does
+    let newest = articles.posts.iter().map(|post| post.updated).max();
+
+    writer
+        .create_element("urlset")
+        .with_attribute(("xmlns", "http://www.sitemaps.org/schemas/sitemap/0.9"))
+        .write_inner_content(|writer| {
+            url(writer, &format!("{}/", base_url), newest)?;
+            url(writer, &format!("{}/articles", base_url), newest)?;
+            url(writer, &format!("{}/tags", base_url), newest)?;
+            for page in pages.iter() {
+                url(writer, &format!("{}/{}", base_url, page.slug), None)?;
+            }
+            for post in articles.posts.iter() {
+                let loc = format!("{}/article/{}", base_url, post.slug);
+                url(writer, &loc, Some(post.updated))?;
+            }
+            Ok(())
+        })?;
+
+    String::from_utf8(writer.into_inner()).map_err(io::Error::other)
+}
+
+fn url<W: io::Write>(
+    writer: &mut Writer<W>,
+    loc: &str,
+    lastmod: Option<OffsetDateTime>,
+) -> io::Result<()> {
+    writer.create_element("url").write_inner_content(|writer| {
+        writer
+            .create_element("loc")
+            .write_text_content(BytesText::new(loc))?;
+        if let Some(date) = lastmod {
+            writer
+                .create_element("lastmod")
+                .write_text_content(BytesText::new(&rfc3339(date)?))?;
+        }
+        Ok(())
+    })?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::post::Article;
+
+    use std::collections::BTreeMap;
+    use std::path::Path;
+
+    #[test]
+    fn test_build() {
+        let header: BTreeMap<String, String> = [
+            ("Title", "Hello"),
+            ("Date", "2023-04-12"),
+            ("Updated", "2023-05-01 09:30 +02:00"),
+        ]
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect();
+        let mut articles = Articles::default();
+        articles
+            .posts
+            .push(Article::from_header(Path::new("hello.md"), &header).unwrap());
+
+        let xml = build("https://example.com", &articles, &Pages::default()).unwrap();
+        assert!(xml.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>"));
+        assert!(xml.contains("<loc>https://example.com/</loc>"));
+        assert!(xml.contains("<loc>https://example.com/tags</loc>"));
+        assert!(xml.contains(
+            "<loc>https://example.com/article/hello</loc>\n    \
+             <lastmod>2023-05-01T07:30:00Z</lastmod>"
+        ));
+        assert_eq!(xml.matches("<url>").count(), 4);
+        assert_eq!(xml.matches("<lastmod>").count(), 4);
+    }
+}
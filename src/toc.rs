@@ -0,0 +1,146 @@
+//! Anchors on the headings of the rendered markdown, and the table of contents
+//! built from them
+
+use crate::md_ex;
+
+/// replaced by the table of contents, as rendered by the markdown parser
+pub const MARKER: &str = "<p>[[toc]]</p>";
+
+/// only the sections are listed, the `h1` is the title of the page
+const TOC_LEVELS: std::ops::RangeInclusive<u8> = 2..=6;
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Heading {
+    pub level: u8,
+    /// derived from the text, so links survive edits to other headings
+    pub id: String,
+    /// text of the heading, still html escaped
+    pub title: String,
+}
+
+/// gives every `<hN>` an `id` and a permalink shown on hover, returns the
+/// headings in the order they appear
+pub fn anchor_headings(html: &str) -> (String, Vec<Heading>) {
+    let mut out = String::with_capacity(html.len());
+    let mut headings: Vec<Heading> = Vec::new();
+    let mut rest = html;
+
+    while let Some((start, level)) = find_heading(rest) {
+        let close = format!("</h{}>", level);
+        let inner_start = start + "<hN>".len();
+        let Some(inner_len) = rest[inner_start..].find(&close) else {
+            break;
+        };
+        let inner = &rest[inner_start..inner_start + inner_len];
+
+        let title = strip_tags(inner);
+        let mut id = md_ex::slugify(&md_ex::unescape_html(&title));
+        if id.is_empty() {
+            id = "section".to_string();
+        }
+        // the first heading keeps the plain id, the next ones get a suffix
+        let base = id.clone();
+        let mut n = 1;
+        while headings.iter().any(|h| h.id == id) {
+            id = format!("{}-{}", base, n);
+            n += 1;
+        }
+
+        out.push_str(&rest[..start]);
+        out.push_str(&format!(
+            "<h{level} id=\"{id}\">{inner}<a class=\"anchor\" href=\"#{id}\" aria-label=\"Permalink\">#</a>{close}"
+        ));
+        headings.push(Heading { level, id, title });
+        rest = &rest[inner_start + inner_len + close.len()..];
+    }
+    out.push_str(rest);
+    (out, headings)
+}
+
+/// nested lists of links to the sections, empty when there are none
+pub fn toc_html(headings: &[Heading]) -> String {
+    if !headings.iter().any(|h| TOC_LEVELS.contains(&h.level)) {
+        return String::new();
+    }
+    let mut out = String::from("<nav class=\"toc\"><h2>Contents</h2>");
+    // levels of the lists currently opened
+    let mut levels: Vec<u8> = Vec::new();
+
+    for heading in headings.iter().filter(|h| TOC_LEVELS.contains(&h.level)) {
+        while levels.last().is_some_and(|l| *l > heading.level) {
+            levels.pop();
+            out.push_str("</li></ul>");
+        }
+        match levels.last() {
+            Some(l) if *l == heading.level => out.push_str("</li>"),
+            _ => {
+                levels.push(heading.level);
+                out.push_str("<ul>");
+            }
+        }
+        out.push_str(&format!(
+            "<li><a href=\"#{}\">{}</a>",
+            heading.id, heading.title
+        ));
+    }
+    for _ in levels {
+        out.push_str("</li></ul>");
+    }
+    out.push_str("</nav>");
+    out
+}
+
+/// start of the next `<hN>` and its level
+fn find_heading(html: &str) -> Option<(usize, u8)> {
+    let bytes = html.as_bytes();
+    html.match_indices("<h").find_map(|(i, _)| {
+        let level = *bytes.get(i + 2)?;
+        let is_heading = (b'1'..=b'6').contains(&level) && bytes.get(i + 3) == Some(&b'>');
+        is_heading.then_some((i, level - b'0'))
+    })
+}
+
+fn strip_tags(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => (),
+        }
+    }
+    text
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_anchor_headings() {
+        let (html, headings) = anchor_headings(
+            "<h1>Title</h1>\n<h2>Why <code>&amp;mut</code>?</h2>\n<h3>Why</h3>\n<h2>Why</h2>\n",
+        );
+        assert!(html.starts_with("<h1 id=\"title\">Title<a class=\"anchor\" href=\"#title\""));
+        let ids: Vec<_> = headings.iter().map(|h| h.id.as_str()).collect();
+        assert_eq!(ids, vec!["title", "why-mut", "why", "why-1"]);
+        assert_eq!(headings[1].title, "Why &amp;mut?");
+        assert_eq!(headings[2].level, 3);
+
+        assert_eq!(
+            toc_html(&headings),
+            "<nav class=\"toc\"><h2>Contents</h2><ul>\
+             <li><a href=\"#why-mut\">Why &amp;mut?</a><ul><li><a href=\"#why\">Why</a></li></ul></li>\
+             <li><a href=\"#why-1\">Why</a></li></ul></nav>"
+        );
+    }
+
+    #[test]
+    fn test_toc_without_sections() {
+        assert_eq!(toc_html(&[]), "");
+        let (_, headings) = anchor_headings("<h1>Title</h1>\n<p>no sections</p>\n");
+        assert_eq!(toc_html(&headings), "");
+    }
+}